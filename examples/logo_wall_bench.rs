@@ -0,0 +1,59 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                  FICHIER: examples/logo_wall_bench.rs                    ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Mesure du temps CPU par image du mur de logos, sans fenêtre ni GPU
+//!
+//! `cargo run --release --example logo_wall_bench -- [N] [images]`
+//!
+//! Sans `RenderPlugin`, il n'y a ni extraction ni préparation du rendu : le
+//! chiffre couvre seulement `logo_wall_animation_system` et la propagation
+//! des transforms, pas le coût du regroupement en lots. Celui-ci se
+//! mesure dans l'application, mur affiché (touche Q).
+
+use bevy::prelude::*;
+use std::time::Instant;
+use logo_rust_bevy_3d_20251001::cache::MaterialCache;
+use logo_rust_bevy_3d_20251001::systems::logo_wall::{logo_wall_animation_system, spawn_logo_wall, WallInstance};
+
+fn main() {
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<usize>());
+    let count = args.next().and_then(Result::ok).unwrap_or(1000);
+    let frames = args.next().and_then(Result::ok).unwrap_or(300);
+
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), TransformPlugin))
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_resource::<MaterialCache>()
+        .add_systems(Update, logo_wall_animation_system);
+
+    let setup_start = Instant::now();
+    let world = app.world_mut();
+    world.resource_scope(|world, mut meshes: Mut<Assets<Mesh>>| {
+        world.resource_scope(|world, mut materials: Mut<Assets<StandardMaterial>>| {
+            world.resource_scope(|world, mut cache: Mut<MaterialCache>| {
+                let mut commands = world.commands();
+                spawn_logo_wall(&mut commands, &mut meshes, &mut materials, &mut cache, count, Vec3::ZERO);
+            });
+        });
+    });
+    app.world_mut().flush();
+    let setup_time = setup_start.elapsed();
+
+    // Préchauffage : premières images (propagation initiale, allocations)
+    for _ in 0..10 {
+        app.update();
+    }
+
+    let start = Instant::now();
+    for _ in 0..frames {
+        app.update();
+    }
+    let per_frame = start.elapsed().as_secs_f64() * 1000.0 / frames as f64;
+
+    let instances = app.world_mut().query::<&WallInstance>().iter(app.world()).count();
+    println!("Mur de {} logos", instances);
+    println!("   • Création : {:.1} ms", setup_time.as_secs_f64() * 1000.0);
+    println!("   • Temps CPU par image : {:.3} ms ({} images)", per_frame, frames);
+}
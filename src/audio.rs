@@ -0,0 +1,306 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: src/audio.rs                            ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Analyse audio d'un fichier WAV
+//!
+//! Lecture du fichier, puis calcul hors ligne, image par image, de
+//! l'amplitude (RMS) et de l'énergie par bandes de fréquences (FFT). Aucun
+//! périphérique audio n'est nécessaire.
+
+use bevy::prelude::*;
+use std::f32::consts::PI;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::file_io::invalid_data;
+
+/// Signal audio décodé, ramené en mono
+#[derive(Clone, Debug, PartialEq)]
+pub struct WavData {
+    pub sample_rate: u32,
+    /// Échantillons mono dans [-1, 1]
+    pub samples: Vec<f32>,
+}
+
+impl WavData {
+    /// Durée du signal en secondes
+    pub fn duration(&self) -> f32 {
+        self.samples.len() as f32 / self.sample_rate.max(1) as f32
+    }
+}
+
+/// Résultat de l'analyse pour une image
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AudioFrame {
+    /// Amplitude RMS normalisée (0 à 1 sur l'ensemble du morceau)
+    pub amplitude: f32,
+    /// Énergie par bande, des graves aux aigus, normalisée (0 à 1)
+    pub bands: Vec<f32>,
+}
+
+/// Analyse complète d'un morceau
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AudioAnalysis {
+    /// Images d'analyse par seconde
+    pub fps: f32,
+    pub frames: Vec<AudioFrame>,
+}
+
+impl AudioAnalysis {
+    /// Image d'analyse correspondant à l'instant `time`
+    pub fn frame_at(&self, time: f32) -> Option<&AudioFrame> {
+        if time < 0.0 {
+            return None;
+        }
+        self.frames.get((time * self.fps) as usize)
+    }
+
+    /// Durée analysée en secondes
+    pub fn duration(&self) -> f32 {
+        self.frames.len() as f32 / self.fps.max(f32::EPSILON)
+    }
+}
+
+/// Décode un fichier WAV (PCM 8/16/24/32 bits ou flottant 32 bits)
+pub fn parse_wav(bytes: &[u8]) -> io::Result<WavData> {
+    if bytes.len() < 12 || &bytes[0..4] != b"RIFF" || &bytes[8..12] != b"WAVE" {
+        return Err(invalid_data("en-tête RIFF/WAVE absent"));
+    }
+
+    let u16_at = |i: usize| u16::from_le_bytes([bytes[i], bytes[i + 1]]);
+    let u32_at = |i: usize| u32::from_le_bytes([bytes[i], bytes[i + 1], bytes[i + 2], bytes[i + 3]]);
+
+    let mut format = None;
+    let mut data = None;
+    let mut offset = 12;
+    while offset + 8 <= bytes.len() {
+        let id = &bytes[offset..offset + 4];
+        let size = u32_at(offset + 4) as usize;
+        let body = offset + 8;
+        let end = (body + size).min(bytes.len());
+        match id {
+            b"fmt " => {
+                // Taille vérifiée sur les octets présents, pas sur la taille déclarée
+                if end - body < 16 {
+                    return Err(invalid_data("bloc fmt tronqué"));
+                }
+                // (format, canaux, fréquence, bits par échantillon)
+                format = Some((u16_at(body), u16_at(body + 2), u32_at(body + 4), u16_at(body + 14)));
+            }
+            b"data" => data = Some(&bytes[body..end]),
+            _ => {}
+        }
+        // Les blocs sont alignés sur 2 octets
+        offset = body + size + (size & 1);
+    }
+
+    let (format, channels, sample_rate, bits) = format.ok_or_else(|| invalid_data("bloc fmt absent"))?;
+    let data = data.ok_or_else(|| invalid_data("bloc data absent"))?;
+    if channels == 0 {
+        return Err(invalid_data("aucun canal"));
+    }
+
+    // 1 : PCM entier, 3 : flottant, 0xFFFE : extensible (supposé PCM entier)
+    let decode: fn(&[u8]) -> f32 = match (format, bits) {
+        (1 | 0xFFFE, 8) => |b| (b[0] as f32 - 128.0) / 128.0,
+        (1 | 0xFFFE, 16) => |b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0,
+        (1 | 0xFFFE, 24) => |b| (i32::from_le_bytes([0, b[0], b[1], b[2]]) >> 8) as f32 / 8_388_608.0,
+        (1 | 0xFFFE, 32) => |b| i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as f32 / 2_147_483_648.0,
+        (3, 32) => |b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+        _ => return Err(invalid_data("format d'échantillon non pris en charge")),
+    };
+
+    let sample_size = bits as usize / 8;
+    let frame_size = sample_size * channels as usize;
+    let samples = data
+        .chunks_exact(frame_size)
+        .map(|frame| {
+            frame.chunks_exact(sample_size).map(decode).sum::<f32>() / channels as f32
+        })
+        .collect();
+
+    Ok(WavData { sample_rate, samples })
+}
+
+/// Charge et décode un fichier WAV
+pub fn load_wav(path: impl AsRef<Path>) -> io::Result<WavData> {
+    parse_wav(&fs::read(path)?)
+}
+
+/// Transformée de Fourier rapide en place (radix 2), nombres complexes en `Vec2`
+pub fn fft(buffer: &mut [Vec2]) {
+    let n = buffer.len();
+    assert!(n.is_power_of_two(), "la taille de la FFT doit être une puissance de 2");
+
+    // Permutation par inversion des bits
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buffer.swap(i, j);
+        }
+    }
+
+    // Papillons
+    let mut len = 2;
+    while len <= n {
+        let angle = -2.0 * PI / len as f32;
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let w = Vec2::from_angle(angle * k as f32);
+                let a = buffer[start + k];
+                let b = buffer[start + k + len / 2];
+                let t = Vec2::new(w.x * b.x - w.y * b.y, w.x * b.y + w.y * b.x);
+                buffer[start + k] = a + t;
+                buffer[start + k + len / 2] = a - t;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+/// Bornes (en Hz) des bandes de fréquences, réparties logarithmiquement
+pub fn band_edges(band_count: usize, sample_rate: u32) -> Vec<f32> {
+    let low: f32 = 40.0;
+    let high = (sample_rate as f32 / 2.0).min(16_000.0);
+    (0..=band_count)
+        .map(|i| low * (high / low).powf(i as f32 / band_count as f32))
+        .collect()
+}
+
+/// Analyse un signal : amplitude et bandes de fréquences image par image
+///
+/// Les valeurs sont normalisées par leur maximum sur tout le morceau.
+pub fn analyze(wav: &WavData, fps: f32, fft_size: usize, band_count: usize) -> AudioAnalysis {
+    let hop = wav.sample_rate as f32 / fps;
+    let frame_count = (wav.samples.len() as f32 / hop).ceil() as usize;
+    let edges = band_edges(band_count, wav.sample_rate);
+    let bin_hz = wav.sample_rate as f32 / fft_size as f32;
+
+    let mut frames: Vec<AudioFrame> = Vec::with_capacity(frame_count);
+    let mut buffer = vec![Vec2::ZERO; fft_size];
+    for f in 0..frame_count {
+        // Fenêtre de Hann centrée sur l'instant de l'image
+        let center = (f as f32 * hop) as isize;
+        let mut sum_squares = 0.0;
+        for (i, value) in buffer.iter_mut().enumerate() {
+            let index = center + i as isize - fft_size as isize / 2;
+            let sample = usize::try_from(index)
+                .ok()
+                .and_then(|index| wav.samples.get(index))
+                .copied()
+                .unwrap_or(0.0);
+            sum_squares += sample * sample;
+            let window = 0.5 - 0.5 * (2.0 * PI * i as f32 / fft_size as f32).cos();
+            *value = Vec2::new(sample * window, 0.0);
+        }
+        fft(&mut buffer);
+
+        let mut bands = vec![0.0; band_count];
+        for (bin, value) in buffer.iter().enumerate().take(fft_size / 2).skip(1) {
+            let frequency = bin as f32 * bin_hz;
+            if let Some(band) = edges.windows(2).position(|e| frequency >= e[0] && frequency < e[1]) {
+                bands[band] += value.length_squared();
+            }
+        }
+
+        frames.push(AudioFrame {
+            amplitude: (sum_squares / fft_size as f32).sqrt(),
+            bands: bands.into_iter().map(f32::sqrt).collect(),
+        });
+    }
+
+    // === NORMALISATION SUR LE MORCEAU ===
+    let max_amplitude = frames.iter().map(|f| f.amplitude).fold(0.0, f32::max);
+    let max_band = frames.iter().flat_map(|f| f.bands.iter().copied()).fold(0.0, f32::max);
+    for frame in &mut frames {
+        if max_amplitude > 0.0 {
+            frame.amplitude /= max_amplitude;
+        }
+        if max_band > 0.0 {
+            frame.bands.iter_mut().for_each(|b| *b /= max_band);
+        }
+    }
+
+    AudioAnalysis { fps, frames }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Écrit un WAV PCM 16 bits stéréo contenant une sinusoïde
+    fn sine_wav(frequency: f32, sample_rate: u32, seconds: f32) -> Vec<u8> {
+        let count = (sample_rate as f32 * seconds) as usize;
+        let mut data = Vec::new();
+        for i in 0..count {
+            let value = (2.0 * PI * frequency * i as f32 / sample_rate as f32).sin() * 0.5;
+            let sample = ((value * 32767.0) as i16).to_le_bytes();
+            data.extend_from_slice(&sample);
+            data.extend_from_slice(&sample);
+        }
+
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(b"RIFF");
+        bytes.extend_from_slice(&(36 + data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(b"WAVEfmt ");
+        bytes.extend_from_slice(&16u32.to_le_bytes());
+        bytes.extend_from_slice(&1u16.to_le_bytes());
+        bytes.extend_from_slice(&2u16.to_le_bytes());
+        bytes.extend_from_slice(&sample_rate.to_le_bytes());
+        bytes.extend_from_slice(&(sample_rate * 4).to_le_bytes());
+        bytes.extend_from_slice(&4u16.to_le_bytes());
+        bytes.extend_from_slice(&16u16.to_le_bytes());
+        bytes.extend_from_slice(b"data");
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&data);
+        bytes
+    }
+
+    #[test]
+    fn analyzes_a_wav_file() {
+        let path = std::env::temp_dir().join("logo_rust_audio_test.wav");
+        fs::write(&path, sine_wav(440.0, 44_100, 1.0)).unwrap();
+        let wav = load_wav(&path).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(wav.sample_rate, 44_100);
+        assert!((wav.duration() - 1.0).abs() < 1e-3);
+
+        let analysis = analyze(&wav, 60.0, 1024, 12);
+        assert_eq!(analysis.frames.len(), 60);
+
+        // L'énergie se concentre dans la bande contenant 440 Hz
+        let edges = band_edges(12, 44_100);
+        let expected = edges.windows(2).position(|e| (e[0]..e[1]).contains(&440.0)).unwrap();
+        let frame = analysis.frame_at(0.5).unwrap();
+        let loudest = frame.bands.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap().0;
+        assert_eq!(loudest, expected);
+        assert!(frame.amplitude > 0.9);
+    }
+
+    #[test]
+    fn rejects_non_wav_data() {
+        assert!(parse_wav(b"not a wav file").is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_headers() {
+        let wav = sine_wav(440.0, 8_000, 0.1);
+        // Coupé au milieu du bloc fmt (taille déclarée : 16 octets), puis juste après
+        for length in [20, 24, 30, 36] {
+            let error = parse_wav(&wav[..length]).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+        // Bloc fmt déclaré trop court
+        let mut short = wav.clone();
+        short[16..20].copy_from_slice(&8u32.to_le_bytes());
+        assert!(parse_wav(&short).is_err());
+    }
+}
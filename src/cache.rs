@@ -0,0 +1,245 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: src/cache.rs                            ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Caches de matériaux et de meshes
+//!
+//! Deux parties aux paramètres identiques partagent le même asset : moins
+//! d'allocations, un meilleur regroupement des appels de rendu, et un
+//! changement de palette ne touche qu'un seul matériau.
+//!
+//! Un matériau partagé peut être modifié sur place (palette, préréglages,
+//! animations) : la modification vaut pour toutes les parties qui le
+//! partagent, toutes de la même catégorie. La clé est revérifiée à chaque
+//! demande, si bien qu'un matériau modifié n'est plus distribué sous ses
+//! anciens paramètres.
+
+use bevy::prelude::*;
+use bevy::pbr::{OpaqueRendererMethod, UvChannel};
+use bevy::render::render_resource::Face;
+use std::collections::HashMap;
+
+/// Clé d'un matériau : tous ses paramètres de rendu, flottants comparés bit à bit
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MaterialKey {
+    /// Couleurs de base, d'émission, de teinte spéculaire et d'atténuation
+    colors: [[u32; 4]; 4],
+    /// Paramètres scalaires (rugosité, métal, vernis, transmission...)
+    scalars: [u32; 17],
+    /// Textures (couleur, émission, métal-rugosité, normales, occlusion, profondeur)
+    textures: [Option<AssetId<Image>>; 6],
+    /// Canaux UV des textures
+    channels: [u8; 5],
+    uv_transform: [u32; 6],
+    /// Double face, sans éclairage, brouillard, normal map retournée en Y
+    flags: [bool; 4],
+    cull_mode: Option<Face>,
+    /// Mode de transparence et éventuel seuil de découpe
+    alpha_mode: (u8, u32),
+    /// Méthode de parallaxe et nombre maximal d'étapes
+    parallax: (u8, u32),
+    /// Méthode de rendu opaque et passe d'éclairage différé
+    render_method: (u8, u8),
+}
+
+impl MaterialKey {
+    pub fn new(material: &StandardMaterial) -> Self {
+        let bits = |v: Vec4| v.to_array().map(f32::to_bits);
+        let channel = |channel: &UvChannel| match channel {
+            UvChannel::Uv0 => 0,
+            UvChannel::Uv1 => 1,
+        };
+        let texture = |handle: &Option<Handle<Image>>| handle.as_ref().map(Handle::id);
+        let alpha_mode = match material.alpha_mode {
+            AlphaMode::Opaque => (0, 0),
+            AlphaMode::Mask(cutoff) => (1, cutoff.to_bits()),
+            AlphaMode::Blend => (2, 0),
+            AlphaMode::Premultiplied => (3, 0),
+            AlphaMode::AlphaToCoverage => (4, 0),
+            AlphaMode::Add => (5, 0),
+            AlphaMode::Multiply => (6, 0),
+        };
+        let parallax = match material.parallax_mapping_method {
+            ParallaxMappingMethod::Occlusion => (0, 0),
+            ParallaxMappingMethod::Relief { max_steps } => (1, max_steps),
+        };
+        let render_method = match material.opaque_render_method {
+            OpaqueRendererMethod::Forward => 0,
+            OpaqueRendererMethod::Deferred => 1,
+            OpaqueRendererMethod::Auto => 2,
+        };
+        Self {
+            colors: [
+                bits(material.base_color.to_linear().to_vec4()),
+                bits(material.emissive.to_vec4()),
+                bits(material.specular_tint.to_linear().to_vec4()),
+                bits(material.attenuation_color.to_linear().to_vec4()),
+            ],
+            scalars: [
+                material.emissive_exposure_weight,
+                material.perceptual_roughness,
+                material.metallic,
+                material.reflectance,
+                material.diffuse_transmission,
+                material.specular_transmission,
+                material.thickness,
+                material.ior,
+                material.attenuation_distance,
+                material.clearcoat,
+                material.clearcoat_perceptual_roughness,
+                material.anisotropy_strength,
+                material.anisotropy_rotation,
+                material.depth_bias,
+                material.parallax_depth_scale,
+                material.max_parallax_layer_count,
+                material.lightmap_exposure,
+            ]
+            .map(f32::to_bits),
+            textures: [
+                texture(&material.base_color_texture),
+                texture(&material.emissive_texture),
+                texture(&material.metallic_roughness_texture),
+                texture(&material.normal_map_texture),
+                texture(&material.occlusion_texture),
+                texture(&material.depth_map),
+            ],
+            channels: [
+                channel(&material.base_color_channel),
+                channel(&material.emissive_channel),
+                channel(&material.metallic_roughness_channel),
+                channel(&material.normal_map_channel),
+                channel(&material.occlusion_channel),
+            ],
+            uv_transform: material.uv_transform.to_cols_array().map(f32::to_bits),
+            flags: [material.double_sided, material.unlit, material.fog_enabled, material.flip_normal_map_y],
+            cull_mode: material.cull_mode,
+            alpha_mode,
+            parallax,
+            render_method: (render_method, material.deferred_lighting_pass_id),
+        }
+    }
+}
+
+/// Ressource partageant les matériaux de paramètres identiques
+#[derive(Resource, Default)]
+pub struct MaterialCache {
+    handles: HashMap<MaterialKey, Handle<StandardMaterial>>,
+}
+
+impl MaterialCache {
+    /// Renvoie le matériau partagé, créé au premier appel
+    ///
+    /// Un matériau modifié sur place depuis ne correspond plus à sa clé : il
+    /// reste à ses parties et un nouveau matériau est créé.
+    pub fn get_or_add(&mut self, materials: &mut Assets<StandardMaterial>, material: StandardMaterial) -> Handle<StandardMaterial> {
+        let key = MaterialKey::new(&material);
+        if let Some(handle) = self.handles.get(&key)
+            && materials.get(handle).is_some_and(|current| MaterialKey::new(current) == key)
+        {
+            return handle.clone();
+        }
+        let handle = materials.add(material);
+        self.handles.insert(key, handle.clone());
+        handle
+    }
+
+    /// Nombre de matériaux distincts
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+/// Clé d'un mesh : le constructeur de `geometry.rs` et ses paramètres
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MeshKey {
+    Ring { outer_radius: u32, inner_radius: u32, depth: u32, segments: usize },
+    Cylinder { radius: u32, depth: u32, segments: usize },
+}
+
+impl MeshKey {
+    pub fn ring(outer_radius: f32, inner_radius: f32, depth: f32, segments: usize) -> Self {
+        Self::Ring {
+            outer_radius: outer_radius.to_bits(),
+            inner_radius: inner_radius.to_bits(),
+            depth: depth.to_bits(),
+            segments,
+        }
+    }
+
+    pub fn cylinder(radius: f32, depth: f32, segments: usize) -> Self {
+        Self::Cylinder { radius: radius.to_bits(), depth: depth.to_bits(), segments }
+    }
+}
+
+/// Ressource partageant les meshes de géométrie identique
+#[derive(Resource, Default)]
+pub struct MeshCache {
+    handles: HashMap<MeshKey, Handle<Mesh>>,
+}
+
+impl MeshCache {
+    /// Renvoie le mesh partagé, construit par `build` au premier appel
+    pub fn get_or_add(&mut self, meshes: &mut Assets<Mesh>, key: MeshKey, build: impl FnOnce() -> Mesh) -> Handle<Mesh> {
+        self.handles.entry(key).or_insert_with(|| meshes.add(build())).clone()
+    }
+
+    /// Nombre de meshes distincts
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry, materials};
+
+    #[test]
+    fn identical_parameters_share_one_asset() {
+        let mut materials = Assets::<StandardMaterial>::default();
+        let mut cache = MaterialCache::default();
+        let a = cache.get_or_add(&mut materials, materials::get_small_circle_material());
+        let b = cache.get_or_add(&mut materials, materials::get_small_circle_material());
+        let c = cache.get_or_add(&mut materials, materials::get_rainbow_material(3));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(materials.len(), 2);
+
+        // Les champs de rendu autres que les couleurs comptent aussi
+        let base = materials::get_r_logo_material();
+        let variants = [
+            StandardMaterial { unlit: true, ..base.clone() },
+            StandardMaterial { double_sided: true, cull_mode: None, ..base.clone() },
+            StandardMaterial { clearcoat: 1.0, ..base.clone() },
+            StandardMaterial { specular_transmission: 0.9, ..base.clone() },
+            StandardMaterial { normal_map_texture: Some(Handle::default()), ..base.clone() },
+        ];
+        let base_handle = cache.get_or_add(&mut materials, base.clone());
+        for variant in variants {
+            assert_ne!(cache.get_or_add(&mut materials, variant), base_handle);
+        }
+
+        // Un matériau partagé modifié sur place n'est plus distribué sous son ancienne clé
+        materials.get_mut(&base_handle).unwrap().base_color = Color::WHITE;
+        let fresh = cache.get_or_add(&mut materials, base);
+        assert_ne!(fresh, base_handle);
+        assert_eq!(materials.get(&fresh).unwrap().base_color, materials::get_r_logo_material().base_color);
+
+        let mut meshes = Assets::<Mesh>::default();
+        let mut cache = MeshCache::default();
+        for _ in 0..5 {
+            cache.get_or_add(&mut meshes, MeshKey::cylinder(15.0, 10.0, 32), || {
+                geometry::create_3d_cylinder_mesh(15.0, 10.0, 32)
+            });
+        }
+        assert_eq!(meshes.len(), 1);
+    }
+}
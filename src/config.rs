@@ -40,3 +40,120 @@ pub const CAMERA_DISTANCE: f32 = 500.0;
 
 /// Angle de la caméra en degrés
 pub const CAMERA_ANGLE: f32 = 25.0;
+
+/// Marge appliquée autour du logo lors du cadrage automatique
+pub const FRAMING_MARGIN: f32 = 1.15;
+
+/// Intervalle (secondes) entre deux keyframes de caméra ajoutées à la main
+pub const CAMERA_PATH_KEY_INTERVAL: f32 = 2.0;
+
+/// Intervalle (secondes) d'échantillonnage en mode enregistrement continu
+pub const CAMERA_PATH_RECORD_INTERVAL: f32 = 0.5;
+
+/// Fichier de sauvegarde du chemin de caméra
+pub const CAMERA_PATH_FILE: &str = "camera_path.txt";
+
+/// Images par seconde de l'export image par image du chemin de caméra
+pub const CAMERA_PATH_EXPORT_FPS: f32 = 30.0;
+
+/// Dossier des images exportées
+pub const CAMERA_PATH_EXPORT_DIR: &str = "turntable";
+
+/// Vitesse de déplacement de la caméra libre (unités par seconde)
+pub const FLY_SPEED: f32 = 200.0;
+
+/// Multiplicateur de vitesse de la caméra libre (touche Maj)
+pub const FLY_BOOST: f32 = 3.0;
+
+/// Sensibilité du regard de la caméra libre (radians par pixel de souris)
+pub const FLY_SENSITIVITY: f32 = 0.003;
+
+/// Angle vertical maximal du regard de la caméra libre, vers le haut comme vers le bas (radians)
+pub const FLY_MAX_PITCH: f32 = 1.5;
+
+/// Angle vertical minimal de la caméra orbitale (radians)
+pub const CAMERA_MIN_PITCH: f32 = -1.5;
+
+/// Angle vertical maximal de la caméra orbitale (radians)
+pub const CAMERA_MAX_PITCH: f32 = 1.5;
+
+/// Écartement radial maximal des pièces en vue éclatée
+pub const EXPLODE_DISTANCE: f32 = 120.0;
+
+/// Écartement en profondeur (Z) entre couches en vue éclatée
+pub const EXPLODE_DEPTH: f32 = 60.0;
+
+/// Durée (secondes) de l'animation d'éclatement / réassemblage
+pub const EXPLODE_DURATION: f32 = 1.5;
+
+/// Fichier de timeline chargé par la touche T
+pub const TIMELINE_FILE: &str = "assets/timeline.txt";
+
+/// Durée totale (secondes) de l'animation d'apparition au démarrage
+pub const INTRO_DURATION: f32 = 4.0;
+
+/// Nombre de points des contours rééchantillonnés pour le morphing
+pub const MORPH_SAMPLES: usize = 48;
+
+/// Durée (secondes) du morphing entre deux glyphes
+pub const MORPH_DURATION: f32 = 2.0;
+
+/// Fichier WAV analysé par l'animation audio-réactive (relatif au dossier `assets`)
+pub const AUDIO_FILE: &str = "music.wav";
+
+/// Nombre de bandes de fréquences de l'analyse audio
+pub const AUDIO_BANDS: usize = 12;
+
+/// Taille de la fenêtre d'analyse FFT (puissance de 2)
+pub const AUDIO_FFT_SIZE: usize = 1024;
+
+/// Nombre d'images d'analyse par seconde
+pub const AUDIO_ANALYSIS_FPS: f32 = 60.0;
+
+/// Fréquence du pas fixe (`FixedUpdate`) des ressorts et de la physique (Hz)
+pub const FIXED_UPDATE_HZ: f64 = 64.0;
+
+/// Hauteur du sol sur lequel rebondissent les pièces brisées
+pub const SHATTER_GROUND_Y: f32 = -260.0;
+
+/// Gravité appliquée aux pièces brisées (unités par seconde²)
+pub const SHATTER_GRAVITY: f32 = 600.0;
+
+/// Durée (secondes) du réassemblage après bris
+pub const REASSEMBLE_DURATION: f32 = 1.5;
+
+/// Nombre de particules préallouées (pool)
+pub const PARTICLE_POOL_SIZE: usize = 600;
+
+/// Nombre de matériaux partagés par courbe de couleur des particules
+pub const PARTICLE_COLOR_STEPS: usize = 16;
+
+/// Fichier de thèmes de couleurs (lu au démarrage, optionnel)
+pub const THEME_FILE: &str = "assets/themes.txt";
+
+/// Fichier d'affectation des préréglages de matériaux (touche V)
+pub const PART_MATERIALS_FILE: &str = "assets/materials.txt";
+
+/// Écart de couleur (ΔE CIELAB) sous lequel deux parties voisines se confondent
+pub const CVD_MIN_DELTA_E: f32 = 6.0;
+
+/// Nombre de logos du mur de logos
+pub const LOGO_WALL_COUNT: usize = 400;
+
+/// Espacement entre deux logos du mur
+pub const LOGO_WALL_SPACING: f32 = 450.0;
+
+/// Nombre de teintes (matériaux partagés) du mur de logos
+pub const LOGO_WALL_TINTS: usize = 8;
+
+/// Segments des niveaux de détail de l'anneau (du plus fin au plus grossier)
+pub const LOD_RING_SEGMENTS: [usize; 3] = [64, 32, 16];
+
+/// Segments des niveaux de détail des petits cercles
+pub const LOD_CIRCLE_SEGMENTS: [usize; 3] = [32, 16, 8];
+
+/// Écart de corde maximal toléré à l'écran (pixels) avant de raffiner
+pub const LOD_MAX_ERROR_PX: f32 = 0.5;
+
+/// Marge d'hystérésis entre deux niveaux de détail (fraction du seuil)
+pub const LOD_HYSTERESIS: f32 = 0.15;
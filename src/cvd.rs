@@ -0,0 +1,174 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                          FICHIER: src/cvd.rs                             ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Simulation des déficiences de la vision des couleurs (daltonisme)
+//!
+//! Les couleurs sont transformées en RGB linéaire par les matrices de
+//! Machado et al. (2009, sévérité maximale). Le rapport d'accessibilité
+//! liste les parties voisines qui se distinguent en vision normale mais se
+//! confondent une fois la déficience simulée.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::palette::Theme;
+use crate::systems::setup::LogoPart;
+
+/// Déficience simulée
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorDeficiency {
+    /// Absence de cônes L (rouge)
+    Protanopia,
+    /// Absence de cônes M (vert)
+    Deuteranopia,
+    /// Absence de cônes S (bleu)
+    Tritanopia,
+}
+
+impl ColorDeficiency {
+    pub const ALL: [ColorDeficiency; 3] = [Self::Protanopia, Self::Deuteranopia, Self::Tritanopia];
+
+    /// Matrice de simulation (lignes, RGB linéaire)
+    fn matrix(self) -> Mat3 {
+        let rows = match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+        };
+        Mat3::from_cols_array_2d(&rows).transpose()
+    }
+
+    /// Couleur telle que perçue avec cette déficience (l'alpha est conservé)
+    pub fn simulate(self, color: Color) -> Color {
+        let linear = color.to_linear();
+        let rgb = (self.matrix() * Vec3::new(linear.red, linear.green, linear.blue)).clamp(Vec3::ZERO, Vec3::ONE);
+        LinearRgba::new(rgb.x, rgb.y, rgb.z, linear.alpha).into()
+    }
+}
+
+impl std::fmt::Display for ColorDeficiency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Protanopia => write!(f, "protanopie"),
+            Self::Deuteranopia => write!(f, "deutéranopie"),
+            Self::Tritanopia => write!(f, "tritanopie"),
+        }
+    }
+}
+
+/// Écart perceptuel ΔE (CIE76) entre deux couleurs, alpha ignoré
+pub fn delta_e(a: Color, b: Color) -> f32 {
+    let (a, b) = (Laba::from(a), Laba::from(b));
+    // Bevy stocke L, a et b divisés par 100
+    Vec3::new(a.lightness, a.a, a.b).distance(Vec3::new(b.lightness, b.a, b.b)) * 100.0
+}
+
+/// Couples de parties voisines dans le logo
+pub fn adjacent_parts() -> Vec<(LogoPart, LogoPart)> {
+    let mut pairs = Vec::new();
+    let exterior = config::EXTERIOR_TRIANGLES_COUNT;
+    let interior = config::INTERIOR_TRIANGLES_COUNT;
+    for i in 0..exterior {
+        pairs.push((LogoPart::ExteriorTriangle(i), LogoPart::ExteriorTriangle((i + 1) % exterior)));
+        pairs.push((LogoPart::ExteriorTriangle(i), LogoPart::Ring));
+    }
+    for i in 0..interior {
+        pairs.push((LogoPart::InteriorTriangle(i), LogoPart::InteriorTriangle((i + 1) % interior)));
+        pairs.push((LogoPart::InteriorTriangle(i), LogoPart::Ring));
+        pairs.push((LogoPart::InteriorTriangle(i), LogoPart::SmallCircle(i)));
+    }
+    // Le R est posé sur les triangles intérieurs, à l'intérieur de l'anneau
+    let r = LogoPart::RPart(geometry::get_all_r_parts()[0].name);
+    pairs.push((r, LogoPart::Ring));
+    for i in 0..interior {
+        pairs.push((r, LogoPart::InteriorTriangle(i)));
+    }
+    pairs
+}
+
+/// Couple de parties voisines confondues sous une déficience
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConfusedPair {
+    pub a: LogoPart,
+    pub b: LogoPart,
+    /// Écart en vision normale
+    pub normal_delta_e: f32,
+    /// Écart avec la déficience simulée
+    pub simulated_delta_e: f32,
+}
+
+/// Liste les parties voisines qui deviennent indiscernables sous `deficiency`
+pub fn confused_pairs(theme: &Theme, deficiency: ColorDeficiency) -> Vec<ConfusedPair> {
+    adjacent_parts()
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let (color_a, color_b) = (theme.part_color(&a), theme.part_color(&b));
+            let normal_delta_e = delta_e(color_a, color_b);
+            let simulated_delta_e = delta_e(deficiency.simulate(color_a), deficiency.simulate(color_b));
+            (normal_delta_e >= config::CVD_MIN_DELTA_E && simulated_delta_e < config::CVD_MIN_DELTA_E)
+                .then_some(ConfusedPair { a, b, normal_delta_e, simulated_delta_e })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::palette::ExteriorColors;
+
+    #[test]
+    fn grays_are_unchanged() {
+        for deficiency in ColorDeficiency::ALL {
+            for gray in [Color::BLACK, Color::WHITE, Color::srgb(0.5, 0.5, 0.5)] {
+                assert!(delta_e(deficiency.simulate(gray), gray) < 1.0, "{deficiency}");
+            }
+        }
+    }
+
+    #[test]
+    fn red_green_neighbours_are_reported() {
+        let theme = Theme {
+            interior: vec![Color::srgb(0.85, 0.25, 0.2), Color::srgb(0.4, 0.55, 0.15)],
+            ..Theme::classic()
+        };
+        let report = confused_pairs(&theme, ColorDeficiency::Deuteranopia);
+        assert!(report.iter().any(|pair| matches!(
+            (pair.a, pair.b),
+            (LogoPart::InteriorTriangle(_), LogoPart::InteriorTriangle(_))
+        )));
+
+    }
+
+    #[test]
+    fn contrasting_neighbours_are_not_reported() {
+        // Voisins séparés par la luminosité plutôt que par la teinte
+        let (blue, yellow) = (Color::srgb(0.0, 0.45, 1.0), Color::srgb(1.0, 0.85, 0.0));
+        let theme = Theme {
+            name: "test".to_string(),
+            background: Color::BLACK,
+            ring: Color::WHITE,
+            exterior: ExteriorColors::Gradient(vec![Color::BLACK, Color::srgb(0.2, 0.2, 0.2)]),
+            interior: vec![blue, yellow],
+            small_circle: Color::BLACK,
+            r: Color::BLACK,
+        };
+        for deficiency in ColorDeficiency::ALL {
+            // Les voisins intérieurs restent bien distincts une fois simulés
+            let simulated = delta_e(deficiency.simulate(blue), deficiency.simulate(yellow));
+            assert!(simulated >= config::CVD_MIN_DELTA_E, "{deficiency} : ΔE {simulated:.1}");
+            assert!(confused_pairs(&theme, deficiency).is_empty(), "{deficiency}");
+        }
+    }
+}
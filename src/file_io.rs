@@ -0,0 +1,22 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                         FICHIER: src/file_io.rs                          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Erreurs communes aux lecteurs de fichiers
+//!
+//! Chemins de caméra, timelines, thèmes et WAV signalent un contenu
+//! invalide par `io::ErrorKind::InvalidData`, comme les erreurs de lecture.
+
+use std::fmt::Display;
+use std::io;
+
+/// Erreur de contenu invalide
+pub fn invalid_data(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+/// Erreur de contenu invalide à une ligne d'un fichier texte
+/// (`index` : indice de la ligne à partir de 0, affiché à partir de 1)
+pub fn line_error(index: usize, message: impl Display) -> io::Error {
+    invalid_data(format!("ligne {} : {}", index + 1, message))
+}
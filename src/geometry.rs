@@ -4,14 +4,61 @@
 // ╚══════════════════════════════════════════════════════════════════════════╝
 
 use bevy::prelude::*;
-use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::mesh::{Indices, PrimitiveTopology, VertexAttributeValues};
 use std::f32::consts::PI;
+use crate::config;
 
 /// Convertit degrés en radians
 pub fn degrees_to_radians(degrees: f32) -> f32 {
     degrees * PI / 180.0
 }
 
+/// Finesse d'un contour circulaire
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tessellation {
+    /// Nombre de segments explicite
+    Segments(usize),
+    /// Écart maximal (unités du monde) entre une corde et le cercle
+    ChordError(f32),
+    /// Écart maximal en pixels, pour une échelle donnée à l'écran
+    ChordErrorPixels { max_error: f32, pixels_per_unit: f32 },
+}
+
+impl Tessellation {
+    /// Plus grand nombre de segments produit par un écart de corde
+    pub const MAX_SEGMENTS: usize = 1024;
+
+    /// Nombre de segments pour un cercle de rayon `radius`
+    pub fn segments_for(self, radius: f32) -> usize {
+        let max_error = match self {
+            Tessellation::Segments(segments) => return segments.max(3),
+            Tessellation::ChordError(max_error) => max_error,
+            Tessellation::ChordErrorPixels { max_error, pixels_per_unit } => max_error / pixels_per_unit.max(f32::EPSILON),
+        };
+        if max_error >= radius {
+            return 3;
+        }
+        // Une corde sous-tendant l'angle θ s'écarte du cercle de r(1 - cos(θ/2))
+        let half_angle = (1.0 - max_error.max(0.0) / radius).acos();
+        ((PI / half_angle.max(f32::EPSILON)).ceil() as usize).clamp(3, Self::MAX_SEGMENTS)
+    }
+}
+
+/// Écart maximal entre une corde et un cercle de `segments` segments
+pub fn chord_error(radius: f32, segments: usize) -> f32 {
+    radius * (1.0 - (PI / segments.max(3) as f32).cos())
+}
+
+/// Crée un anneau 3D dont la finesse suit `tessellation` (rayon extérieur)
+pub fn create_3d_ring_mesh_with(outer_radius: f32, inner_radius: f32, depth: f32, tessellation: Tessellation) -> Mesh {
+    create_3d_ring_mesh(outer_radius, inner_radius, depth, tessellation.segments_for(outer_radius))
+}
+
+/// Crée un cylindre 3D dont la finesse suit `tessellation`
+pub fn create_3d_cylinder_mesh_with(radius: f32, depth: f32, tessellation: Tessellation) -> Mesh {
+    create_3d_cylinder_mesh(radius, depth, tessellation.segments_for(radius))
+}
+
 /// Crée un anneau 3D avec épaisseur
 pub fn create_3d_ring_mesh(
     outer_radius: f32,
@@ -19,214 +66,452 @@ pub fn create_3d_ring_mesh(
     depth: f32,
     segments: usize,
 ) -> Mesh {
-    let mut positions = Vec::new();
-    let mut normals = Vec::new();
-    let mut indices: Vec<u32> = Vec::new();  // CORRECTION: Vec<u32> au lieu de Vec::new()
-
-    // Pour chaque segment
-    for i in 0..=segments {
-        let angle = 2.0 * PI * i as f32 / segments as f32;
-        let cos = angle.cos();
-        let sin = angle.sin();
+    create_3d_ring_arc_mesh(outer_radius, inner_radius, depth, segments, 2.0 * PI)
+}
 
-        // Face avant (z = depth/2)
-        positions.push([outer_radius * cos, outer_radius * sin, depth / 2.0]);
-        normals.push([0.0, 0.0, 1.0]);
-        
-        positions.push([inner_radius * cos, inner_radius * sin, depth / 2.0]);
-        normals.push([0.0, 0.0, 1.0]);
+/// Crée un arc d'anneau 3D avec épaisseur, de l'angle 0 à `arc` (radians)
+///
+/// Le nombre de segments est proportionnel à l'arc ; un arc incomplet est
+/// fermé à ses deux extrémités. Les faces sont orientées vers l'extérieur
+/// (sens direct) et chaque face a ses propres sommets et normales.
+pub fn create_3d_ring_arc_mesh(
+    outer_radius: f32,
+    inner_radius: f32,
+    depth: f32,
+    full_segments: usize,
+    arc: f32,
+) -> Mesh {
+    let arc = arc.clamp(0.0, 2.0 * PI);
+    let closed = arc >= 2.0 * PI;
+    let segments = if closed {
+        full_segments.max(3)
+    } else {
+        ((full_segments as f32 * arc / (2.0 * PI)).ceil() as usize).max(1)
+    };
+    let half_depth = depth / 2.0;
 
-        // Face arrière (z = -depth/2)
-        positions.push([outer_radius * cos, outer_radius * sin, -depth / 2.0]);
-        normals.push([0.0, 0.0, -1.0]);
-        
-        positions.push([inner_radius * cos, inner_radius * sin, -depth / 2.0]);
-        normals.push([0.0, 0.0, -1.0]);
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    // === FACES AVANT ET ARRIÈRE ===
+    // 4 sommets par angle : avant (extérieur, intérieur), arrière (extérieur,
+    // intérieur). Un anneau complet réutilise les premiers sommets : pas de couture
+    let face_points = if closed { segments } else { segments + 1 };
+    for i in 0..face_points {
+        let angle = arc * i as f32 / segments as f32;
+        let (sin, cos) = angle.sin_cos();
+        for (z, facing) in [(half_depth, 1.0), (-half_depth, -1.0)] {
+            for radius in [outer_radius, inner_radius] {
+                positions.push([radius * cos, radius * sin, z]);
+                normals.push([0.0, 0.0, facing]);
+                uvs.push(face_uv(radius * cos, radius * sin, facing));
+            }
+        }
     }
-
-    // Indices pour les faces avant et arrière
     for i in 0..segments {
-        let base = (i * 4) as u32;  // CORRECTION: cast en u32
-        
-        // Face avant
-        indices.extend_from_slice(&[
-            base, base + 1, base + 4,
-            base + 4, base + 1, base + 5,
-        ]);
-        
-        // Face arrière
+        let a = (i * 4) as u32;
+        let b = (((i + 1) % face_points) * 4) as u32;
         indices.extend_from_slice(&[
-            base + 2, base + 6, base + 3,
-            base + 3, base + 6, base + 7,
+            // Face avant
+            a, b, b + 1,
+            a, b + 1, a + 1,
+            // Face arrière
+            a + 2, b + 3, b + 2,
+            a + 2, a + 3, b + 3,
         ]);
-        
-        // Bord extérieur
-        indices.extend_from_slice(&[
-            base, base + 4, base + 2,
-            base + 2, base + 4, base + 6,
-        ]);
-        
-        // Bord intérieur
+    }
+
+    // === BORDS EXTÉRIEUR ET INTÉRIEUR ===
+    // 4 sommets par angle : bord extérieur (haut, bas), bord intérieur (haut,
+    // bas). La coordonnée U suit l'arc : les bords ont une couture à l'angle 0
+    let walls = positions.len() as u32;
+    for i in 0..=segments {
+        let angle = arc * i as f32 / segments as f32;
+        let (sin, cos) = angle.sin_cos();
+        for (radius, normal) in [(outer_radius, [cos, sin, 0.0]), (inner_radius, [-cos, -sin, 0.0])] {
+            for z in [half_depth, -half_depth] {
+                positions.push([radius * cos, radius * sin, z]);
+                normals.push(normal);
+                uvs.push(side_uv(angle * radius, z));
+            }
+        }
+    }
+    for i in 0..segments {
+        let a = walls + (i * 4) as u32;
+        let b = a + 4;
         indices.extend_from_slice(&[
-            base + 1, base + 3, base + 5,
-            base + 5, base + 3, base + 7,
+            // Bord extérieur
+            a, a + 1, b + 1,
+            a, b + 1, b,
+            // Bord intérieur
+            a + 2, b + 3, a + 3,
+            a + 2, b + 2, b + 3,
         ]);
     }
 
-    Mesh::new(PrimitiveTopology::TriangleList, Default::default())
-        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-        .with_inserted_indices(Indices::U32(indices))
+    // Extrémités d'un arc incomplet, avec leurs propres sommets
+    if !closed {
+        for (angle, outward) in [(0.0, -1.0), (arc, 1.0)] {
+            let (sin, cos) = f32::sin_cos(angle);
+            let normal = [-sin * outward, cos * outward, 0.0];
+            let start = positions.len() as u32;
+            for radius in [inner_radius, outer_radius] {
+                for z in [half_depth, -half_depth] {
+                    positions.push([radius * cos, radius * sin, z]);
+                    normals.push(normal);
+                    uvs.push(side_uv(radius, z));
+                }
+            }
+            // start : intérieur haut, +1 : intérieur bas, +2 : extérieur haut, +3 : extérieur bas
+            if outward < 0.0 {
+                indices.extend_from_slice(&[start, start + 1, start + 3, start, start + 3, start + 2]);
+            } else {
+                indices.extend_from_slice(&[start, start + 3, start + 1, start, start + 2, start + 3]);
+            }
+        }
+    }
+
+    build_mesh(positions, normals, uvs, indices)
 }
 
 /// Crée un cylindre 3D (cercle avec épaisseur)
 pub fn create_3d_cylinder_mesh(radius: f32, depth: f32, segments: usize) -> Mesh {
+    let segments = segments.max(3);
+    let half_depth = depth / 2.0;
     let mut positions = Vec::new();
     let mut normals = Vec::new();
-    let mut indices: Vec<u32> = Vec::new();  // CORRECTION: Vec<u32>
-
-    // Centre face avant
-    positions.push([0.0, 0.0, depth / 2.0]);
-    normals.push([0.0, 0.0, 1.0]);
-
-    // Centre face arrière
-    positions.push([0.0, 0.0, -depth / 2.0]);
-    normals.push([0.0, 0.0, -1.0]);
-
-    // Points du contour (avant et arrière)
-    for i in 0..=segments {
-        let angle = 2.0 * PI * i as f32 / segments as f32;
-        let x = radius * angle.cos();
-        let y = radius * angle.sin();
-
-        // Face avant
-        positions.push([x, y, depth / 2.0]);
-        normals.push([0.0, 0.0, 1.0]);
-
-        // Face arrière
-        positions.push([x, y, -depth / 2.0]);
-        normals.push([0.0, 0.0, -1.0]);
+    let mut uvs = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+
+    // === FACES AVANT ET ARRIÈRE ===
+    // Centres, puis 2 sommets par angle : contour avant, contour arrière
+    for (z, facing) in [(half_depth, 1.0), (-half_depth, -1.0)] {
+        positions.push([0.0, 0.0, z]);
+        normals.push([0.0, 0.0, facing]);
+        uvs.push(face_uv(0.0, 0.0, facing));
     }
-
-    // Triangles face avant
     for i in 0..segments {
-        let front_idx = (2 + i * 2) as u32;  // CORRECTION: cast en u32
-        indices.extend_from_slice(&[0, front_idx, front_idx + 2]);
+        let angle = 2.0 * PI * i as f32 / segments as f32;
+        let (sin, cos) = angle.sin_cos();
+        let (x, y) = (radius * cos, radius * sin);
+        for (z, facing) in [(half_depth, 1.0), (-half_depth, -1.0)] {
+            positions.push([x, y, z]);
+            normals.push([0.0, 0.0, facing]);
+            uvs.push(face_uv(x, y, facing));
+        }
     }
-
-    // Triangles face arrière
     for i in 0..segments {
-        let back_idx = (3 + i * 2) as u32;  // CORRECTION: cast en u32
-        indices.extend_from_slice(&[1, back_idx + 2, back_idx]);
+        let a = (2 + i * 2) as u32;
+        let b = (2 + ((i + 1) % segments) * 2) as u32;
+        indices.extend_from_slice(&[
+            // Face avant
+            0, a, b,
+            // Face arrière
+            1, b + 1, a + 1,
+        ]);
     }
 
-    // Bords latéraux
+    // === BORD LATÉRAL ===
+    // 2 sommets par angle (haut, bas), avec une couture à l'angle 0
+    let wall = positions.len() as u32;
+    for i in 0..=segments {
+        let angle = 2.0 * PI * i as f32 / segments as f32;
+        let (sin, cos) = angle.sin_cos();
+        for z in [half_depth, -half_depth] {
+            positions.push([radius * cos, radius * sin, z]);
+            normals.push([cos, sin, 0.0]);
+            uvs.push(side_uv(angle * radius, z));
+        }
+    }
     for i in 0..segments {
-        let front = (2 + i * 2) as u32;  // CORRECTION: cast en u32
-        let back = (3 + i * 2) as u32;   // CORRECTION: cast en u32
-        indices.extend_from_slice(&[
-            front, front + 2, back,
-            back, front + 2, back + 2,
-        ]);
+        let a = wall + (i * 2) as u32;
+        let b = a + 2;
+        indices.extend_from_slice(&[a, a + 1, b + 1, a, b + 1, b]);
     }
 
-    Mesh::new(PrimitiveTopology::TriangleList, Default::default())
-        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-        .with_inserted_indices(Indices::U32(indices))
+    build_mesh(positions, normals, uvs, indices)
 }
 
 /// Crée un prisme triangulaire 3D
 pub fn create_3d_triangle_mesh(p1: Vec2, p2: Vec2, p3: Vec2, depth: f32) -> Mesh {
-    let half_depth = depth / 2.0;
-
-    let positions = vec![
-        // Face avant
-        [p1.x, p1.y, half_depth],
-        [p2.x, p2.y, half_depth],
-        [p3.x, p3.y, half_depth],
-        // Face arrière
-        [p1.x, p1.y, -half_depth],
-        [p2.x, p2.y, -half_depth],
-        [p3.x, p3.y, -half_depth],
-    ];
-
-    let indices = vec![
-        // Face avant
-        0, 1, 2,
-        // Face arrière
-        3, 5, 4,
-        // Côté 1
-        0, 3, 1, 1, 3, 4,
-        // Côté 2
-        1, 4, 2, 2, 4, 5,
-        // Côté 3
-        2, 5, 0, 0, 5, 3,
-    ];
-
-    // Calcul des normales
-    let normal_front = [0.0, 0.0, 1.0];
-    let normal_back = [0.0, 0.0, -1.0];
-
-    let normals = vec![
-        normal_front, normal_front, normal_front,
-        normal_back, normal_back, normal_back,
-    ];
-
-    Mesh::new(PrimitiveTopology::TriangleList, Default::default())
-        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-        .with_inserted_indices(Indices::U32(indices))
+    create_3d_polygon_mesh(&[p1, p2, p3], depth)
 }
 
 /// Crée un polygone extrudé en 3D
+///
+/// Le contour peut être donné dans les deux sens et être concave : il est
+/// remis dans le sens direct puis triangulé par découpe d'oreilles.
 pub fn create_3d_polygon_mesh(points: &[Vec2], depth: f32) -> Mesh {
     if points.len() < 3 {
         panic!("Besoin de minimum 3 points");
     }
 
+    // Les points alignés n'ajoutent ni face ni arête : le côté reste d'un seul tenant
+    let mut points = remove_collinear_points(points);
+    if polygon_signed_area(&points) < 0.0 {
+        points.reverse();
+    }
+
     let half_depth = depth / 2.0;
     let mut positions = Vec::new();
     let mut normals = Vec::new();
+    let mut uvs = Vec::new();
     let mut indices = Vec::new();
 
-    // Vertices face avant
-    for point in points {
-        positions.push([point.x, point.y, half_depth]);
-        normals.push([0.0, 0.0, 1.0]);
-    }
-
-    // Vertices face arrière
-    for point in points {
-        positions.push([point.x, point.y, -half_depth]);
-        normals.push([0.0, 0.0, -1.0]);
+    // Vertices faces avant puis arrière
+    for (z, facing) in [(half_depth, 1.0), (-half_depth, -1.0)] {
+        for point in &points {
+            positions.push([point.x, point.y, z]);
+            normals.push([0.0, 0.0, facing]);
+            uvs.push(face_uv(point.x, point.y, facing));
+        }
     }
 
     let n = points.len() as u32;
 
-    // Triangulation face avant
-    for i in 1..(n - 1) {
-        indices.extend_from_slice(&[0, i, i + 1]);
-    }
-
-    // Triangulation face arrière
-    for i in 1..(n - 1) {
-        indices.extend_from_slice(&[n, n + i + 1, n + i]);
+    // Triangulation des faces avant et arrière
+    for [a, b, c] in triangulate_polygon(&points) {
+        let (a, b, c) = (a as u32, b as u32, c as u32);
+        indices.extend_from_slice(&[a, b, c]);
+        indices.extend_from_slice(&[n + a, n + c, n + b]);
     }
 
-    // Bords latéraux
-    for i in 0..n {
-        let next = (i + 1) % n;
+    // Bords latéraux : 4 sommets par côté, normale sortante, U le long du contour
+    let mut along = 0.0;
+    for i in 0..points.len() {
+        let (a, b) = (points[i], points[(i + 1) % points.len()]);
+        let (u0, u1) = (along, along + a.distance(b));
+        along = u1;
+        let normal = Vec2::new(b.y - a.y, a.x - b.x).normalize_or_zero();
+        let normal = [normal.x, normal.y, 0.0];
+        let start = positions.len() as u32;
+
+        positions.extend_from_slice(&[
+            [a.x, a.y, half_depth],
+            [a.x, a.y, -half_depth],
+            [b.x, b.y, half_depth],
+            [b.x, b.y, -half_depth],
+        ]);
+        normals.extend_from_slice(&[normal; 4]);
+        uvs.extend_from_slice(&[
+            side_uv(u0, half_depth),
+            side_uv(u0, -half_depth),
+            side_uv(u1, half_depth),
+            side_uv(u1, -half_depth),
+        ]);
         indices.extend_from_slice(&[
-            i, next, i + n,
-            i + n, next, next + n,
+            start, start + 1, start + 3,
+            start, start + 3, start + 2,
         ]);
     }
 
-    Mesh::new(PrimitiveTopology::TriangleList, Default::default())
+    build_mesh(positions, normals, uvs, indices)
+}
+
+/// Triangule un polygone simple dans le sens direct (découpe d'oreilles)
+///
+/// Les sommets alignés avec leurs voisins sont sautés : ils ne produisent
+/// aucun triangle plat. Un contour dégénéré (auto-intersecté) reste
+/// entièrement triangulé : faute d'oreille, le premier sommet convexe est
+/// découpé.
+pub fn triangulate_polygon(points: &[Vec2]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    let mut triangles = Vec::with_capacity(points.len().saturating_sub(2));
+    let cross = |a: usize, b: usize, c: usize| (points[b] - points[a]).perp_dot(points[c] - points[a]);
+    let collinear = |a: usize, b: usize, c: usize| is_collinear(points[a], points[b], points[c]);
+
+    while remaining.len() > 3 {
+        let count = remaining.len();
+        let corner = |i: usize| (remaining[(i + count - 1) % count], remaining[i], remaining[(i + 1) % count]);
+        if let Some(flat) = (0..count).find(|&i| { let (a, b, c) = corner(i); collinear(a, b, c) }) {
+            remaining.remove(flat);
+            continue;
+        }
+        let is_ear = |i: usize| {
+            let (a, b, c) = corner(i);
+            cross(a, b, c) > 0.0
+                && remaining.iter().all(|&p| {
+                    p == a || p == b || p == c || !point_in_triangle(points[p], points[a], points[b], points[c])
+                })
+        };
+
+        let ear = (0..count)
+            .find(|&i| is_ear(i))
+            .or_else(|| (0..count).find(|&i| { let (a, b, c) = corner(i); cross(a, b, c) > 0.0 }))
+            .unwrap_or(0);
+        let (a, b, c) = corner(ear);
+        triangles.push([a, b, c]);
+        remaining.remove(ear);
+    }
+    if let [a, b, c] = remaining[..]
+        && !collinear(a, b, c)
+    {
+        triangles.push([a, b, c]);
+    }
+    triangles
+}
+
+/// Retire d'un contour fermé les points alignés avec leurs voisins
+/// (ainsi que les points répétés)
+pub fn remove_collinear_points(points: &[Vec2]) -> Vec<Vec2> {
+    let mut result = points.to_vec();
+    let mut i = 0;
+    while result.len() > 3 && i < result.len() {
+        let n = result.len();
+        if is_collinear(result[(i + n - 1) % n], result[i], result[(i + 1) % n]) {
+            result.remove(i);
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    result
+}
+
+/// Indique si `b` est aligné avec `a` et `c` (à une tolérance relative près)
+fn is_collinear(a: Vec2, b: Vec2, c: Vec2) -> bool {
+    let (ab, bc) = (b - a, c - b);
+    ab.perp_dot(bc).abs() <= 1e-6 * ab.length() * bc.length() + f32::EPSILON
+}
+
+/// Indique si `p` est dans le triangle direct (a, b, c), bords compris
+fn point_in_triangle(p: Vec2, a: Vec2, b: Vec2, c: Vec2) -> bool {
+    (b - a).perp_dot(p - a) >= 0.0 && (c - b).perp_dot(p - b) >= 0.0 && (a - c).perp_dot(p - c) >= 0.0
+}
+
+/// Taille couverte par une répétition de texture : le diamètre du logo
+const TEXTURE_SIZE: f32 = 2.0 * config::CIRCLE_RADIUS;
+
+/// UV d'une face avant (`facing` = 1) ou arrière (-1), projetés sur XY ;
+/// la face arrière est retournée pour ne pas voir la texture en miroir
+fn face_uv(x: f32, y: f32, facing: f32) -> [f32; 2] {
+    [facing * x / TEXTURE_SIZE + 0.5, 0.5 - y / TEXTURE_SIZE]
+}
+
+/// UV d'un bord : U selon la distance parcourue le long du contour, V selon Z
+fn side_uv(along: f32, z: f32) -> [f32; 2] {
+    [along / TEXTURE_SIZE, 0.5 - z / TEXTURE_SIZE]
+}
+
+/// Assemble un mesh indexé et calcule ses tangentes (mikktspace), pour les
+/// normal maps des préréglages de matériaux
+fn build_mesh(positions: Vec<[f32; 3]>, normals: Vec<[f32; 3]>, uvs: Vec<[f32; 2]>, indices: Vec<u32>) -> Mesh {
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList, Default::default())
         .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
         .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-        .with_inserted_indices(Indices::U32(indices))
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_inserted_indices(Indices::U32(indices));
+    // Tous les attributs requis sont présents : l'échec est impossible ici,
+    // et un mesh sans tangentes reste affichable
+    mesh.generate_tangents().ok();
+    mesh
+}
+
+/// Extrait les positions des sommets d'un mesh (côté CPU)
+pub fn mesh_positions(mesh: &Mesh) -> Vec<Vec3> {
+    match mesh.attribute(Mesh::ATTRIBUTE_POSITION) {
+        Some(VertexAttributeValues::Float32x3(positions)) => {
+            positions.iter().map(|p| Vec3::from_array(*p)).collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Extrait les indices des triangles d'un mesh (côté CPU)
+///
+/// Un mesh sans indices est lu comme une liste de triangles consécutifs.
+pub fn mesh_indices(mesh: &Mesh) -> Vec<u32> {
+    match mesh.indices() {
+        Some(indices) => indices.iter().map(|i| i as u32).collect(),
+        None => (0..mesh.count_vertices() as u32).collect(),
+    }
+}
+
+/// Plages de sommets et d'indices d'une partie dans un mesh fusionné
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MergedRange {
+    pub vertices: std::ops::Range<u32>,
+    pub indices: std::ops::Range<u32>,
+}
+
+/// Fusionne plusieurs meshes en un seul
+///
+/// Chaque mesh est placé par son transform et reçoit une couleur par
+/// sommet. Renvoie le mesh fusionné et, dans l'ordre, la plage occupée par
+/// chaque mesh d'origine.
+pub fn merge_meshes(parts: &[(&Mesh, Transform, LinearRgba)]) -> (Mesh, Vec<MergedRange>) {
+    let mut positions: Vec<[f32; 3]> = Vec::new();
+    let mut normals: Vec<[f32; 3]> = Vec::new();
+    let mut uvs: Vec<[f32; 2]> = Vec::new();
+    let mut colors: Vec<[f32; 4]> = Vec::new();
+    let mut indices: Vec<u32> = Vec::new();
+    let mut ranges = Vec::with_capacity(parts.len());
+
+    for (mesh, transform, color) in parts {
+        let first_vertex = positions.len() as u32;
+        let first_index = indices.len() as u32;
+        let matrix = transform.compute_matrix();
+        let normal_matrix = Mat3::from_mat4(matrix).inverse().transpose();
+
+        let part_positions = mesh_positions(mesh);
+        let part_normals = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+            Some(VertexAttributeValues::Float32x3(values)) => values.clone(),
+            _ => vec![[0.0, 0.0, 1.0]; part_positions.len()],
+        };
+        match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+            Some(VertexAttributeValues::Float32x2(values)) => uvs.extend_from_slice(values),
+            _ => uvs.extend(std::iter::repeat_n([0.0, 0.0], part_positions.len())),
+        }
+        for (position, normal) in part_positions.iter().zip(&part_normals) {
+            positions.push(matrix.transform_point3(*position).to_array());
+            normals.push((normal_matrix * Vec3::from_array(*normal)).normalize_or_zero().to_array());
+            colors.push(color.to_f32_array());
+        }
+        indices.extend(mesh_indices(mesh).iter().map(|i| i + first_vertex));
+
+        ranges.push(MergedRange {
+            vertices: first_vertex..positions.len() as u32,
+            indices: first_index..indices.len() as u32,
+        });
+    }
+
+    let mesh = build_mesh(positions, normals, uvs, indices).with_inserted_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    (mesh, ranges)
+}
+
+/// Calcule le centre de gravité (moyenne) d'un nuage de points
+pub fn calculate_centroid(points: &[Vec3]) -> Vec3 {
+    if points.is_empty() {
+        return Vec3::ZERO;
+    }
+    points.iter().copied().sum::<Vec3>() / points.len() as f32
+}
+
+/// Calcule une sphère englobante (centre, rayon) d'un nuage de points
+///
+/// Le centre est celui de la boîte englobante, le rayon la plus grande
+/// distance d'un point à ce centre.
+pub fn calculate_bounding_sphere(points: &[Vec3]) -> (Vec3, f32) {
+    if points.is_empty() {
+        return (Vec3::ZERO, 0.0);
+    }
+
+    let mut min = points[0];
+    let mut max = points[0];
+    for point in points {
+        min = min.min(*point);
+        max = max.max(*point);
+    }
+
+    let center = (min + max) / 2.0;
+    let radius = points
+        .iter()
+        .map(|p| p.distance(center))
+        .fold(0.0, f32::max);
+
+    (center, radius)
 }
 
 /// Calcule les points d'un triangle extérieur
@@ -288,6 +573,20 @@ pub struct RPartDefinition {
     pub points: Vec<Vec2>,
 }
 
+/// Noms des parties du R, dans l'ordre de `get_all_r_parts`
+///
+/// Permet de retrouver le rang d'une partie sans construire ses contours.
+pub const R_PART_NAMES: [&str; 8] = [
+    "Haut du R",
+    "Gauche du R",
+    "Arrondi du R",
+    "Centre du R",
+    "Pied gauche du R",
+    "Milieu du R",
+    "Jambe droite du R",
+    "Pied droit du R",
+];
+
 /// Retourne toutes les parties du logo R
 pub fn get_all_r_parts() -> Vec<RPartDefinition> {
     vec![
@@ -324,8 +623,8 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
             points: vec![
                 Vec2::new(60.0, 50.0),
                 Vec2::new(40.0, 50.0),
-                Vec2::new(60.0, 10.0),
                 Vec2::new(40.0, 10.0),
+                Vec2::new(60.0, 10.0),
             ],
         },
         RPartDefinition {
@@ -367,3 +666,306 @@ pub fn get_all_r_parts() -> Vec<RPartDefinition> {
         },
     ]
 }
+
+/// Retourne les parties d'un « B », glyphe cible du morphing du R
+pub fn get_all_b_parts() -> Vec<RPartDefinition> {
+    vec![
+        RPartDefinition {
+            name: "Haut du B",
+            points: vec![
+                Vec2::new(-140.0, 90.0),
+                Vec2::new(40.0, 90.0),
+                Vec2::new(40.0, 50.0),
+                Vec2::new(-100.0, 50.0),
+            ],
+        },
+        RPartDefinition {
+            name: "Gauche du B",
+            points: vec![
+                Vec2::new(-80.0, 50.0),
+                Vec2::new(-30.0, 50.0),
+                Vec2::new(-30.0, -50.0),
+                Vec2::new(-80.0, -50.0),
+            ],
+        },
+        RPartDefinition {
+            name: "Boucle haute du B",
+            points: vec![
+                Vec2::new(40.0, 90.0),
+                Vec2::new(80.0, 75.0),
+                Vec2::new(95.0, 45.0),
+                Vec2::new(80.0, 15.0),
+                Vec2::new(40.0, 10.0),
+            ],
+        },
+        RPartDefinition {
+            name: "Milieu du B",
+            points: vec![
+                Vec2::new(50.0, 10.0),
+                Vec2::new(-30.0, 10.0),
+                Vec2::new(-30.0, -30.0),
+                Vec2::new(50.0, -30.0),
+            ],
+        },
+        RPartDefinition {
+            name: "Boucle basse du B",
+            points: vec![
+                Vec2::new(50.0, 10.0),
+                Vec2::new(95.0, 0.0),
+                Vec2::new(115.0, -35.0),
+                Vec2::new(95.0, -70.0),
+                Vec2::new(50.0, -80.0),
+            ],
+        },
+        RPartDefinition {
+            name: "Bas du B",
+            points: vec![
+                Vec2::new(-140.0, -50.0),
+                Vec2::new(50.0, -50.0),
+                Vec2::new(50.0, -80.0),
+                Vec2::new(-140.0, -80.0),
+            ],
+        },
+    ]
+}
+
+/// Aire signée d'un polygone (positive si sens trigonométrique)
+pub fn polygon_signed_area(points: &[Vec2]) -> f32 {
+    let n = points.len();
+    (0..n)
+        .map(|i| points[i].perp_dot(points[(i + 1) % n]))
+        .sum::<f32>()
+        / 2.0
+}
+
+/// Rééchantillonne un contour fermé en `count` points également espacés
+pub fn resample_polygon(points: &[Vec2], count: usize) -> Vec<Vec2> {
+    let n = points.len();
+    if n == 0 || count == 0 {
+        return Vec::new();
+    }
+
+    let lengths: Vec<f32> = (0..n).map(|i| points[i].distance(points[(i + 1) % n])).collect();
+    let perimeter: f32 = lengths.iter().sum();
+    if perimeter <= f32::EPSILON {
+        return vec![points[0]; count];
+    }
+
+    let mut result = Vec::with_capacity(count);
+    let mut edge = 0;
+    let mut edge_start = 0.0;
+    for k in 0..count {
+        let target = perimeter * k as f32 / count as f32;
+        while edge < n - 1 && edge_start + lengths[edge] < target {
+            edge_start += lengths[edge];
+            edge += 1;
+        }
+        let u = if lengths[edge] > 0.0 { (target - edge_start) / lengths[edge] } else { 0.0 };
+        result.push(points[edge].lerp(points[(edge + 1) % n], u.clamp(0.0, 1.0)));
+    }
+    result
+}
+
+/// Prépare deux contours pour le morphing
+///
+/// Les deux contours sont rééchantillonnés au même nombre de points, mis
+/// dans le même sens de parcours, et le second est décalé pour que ses
+/// points soient au plus près de ceux du premier.
+pub fn match_outlines(a: &[Vec2], b: &[Vec2], count: usize) -> (Vec<Vec2>, Vec<Vec2>) {
+    let a = resample_polygon(a, count);
+    let mut b_points = b.to_vec();
+    if polygon_signed_area(a.as_slice()).signum() != polygon_signed_area(&b_points).signum() {
+        b_points.reverse();
+    }
+    let b = resample_polygon(&b_points, count);
+
+    let best_shift = (0..count)
+        .min_by(|&s1, &s2| {
+            let cost = |shift: usize| -> f32 {
+                (0..count).map(|i| a[i].distance_squared(b[(i + shift) % count])).sum()
+            };
+            cost(s1).total_cmp(&cost(s2))
+        })
+        .unwrap_or(0);
+    let b = (0..count).map(|i| b[(i + best_shift) % count]).collect();
+    (a, b)
+}
+
+/// Interpole deux contours appariés (t = 0 : premier, t = 1 : second)
+pub fn interpolate_outlines(a: &[Vec2], b: &[Vec2], t: f32) -> Vec<Vec2> {
+    a.iter().zip(b).map(|(p, q)| p.lerp(*q, t)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chord_error_gives_equal_smoothness() {
+        let tessellation = Tessellation::ChordError(0.1);
+        let ring = tessellation.segments_for(config::CIRCLE_RADIUS);
+        let circle = tessellation.segments_for(config::SMALL_CIRCLE_RADIUS);
+        assert!(ring > circle);
+        for (radius, segments) in [(config::CIRCLE_RADIUS, ring), (config::SMALL_CIRCLE_RADIUS, circle)] {
+            assert!(chord_error(radius, segments) <= 0.1);
+            // Le nombre minimal : un segment de moins dépasserait l'écart
+            assert!(chord_error(radius, segments - 1) > 0.1);
+        }
+
+        let pixels = Tessellation::ChordErrorPixels { max_error: 0.5, pixels_per_unit: 5.0 };
+        assert_eq!(pixels.segments_for(50.0), tessellation.segments_for(50.0));
+        assert_eq!(Tessellation::Segments(64).segments_for(200.0), 64);
+        assert_eq!(Tessellation::ChordError(100.0).segments_for(15.0), 3);
+    }
+
+    #[test]
+    fn tangents_follow_the_uv_layout() {
+        let meshes = [
+            create_3d_ring_mesh(200.0, 180.0, 20.0, 32),
+            create_3d_ring_arc_mesh(200.0, 180.0, 20.0, 32, 2.0),
+            create_3d_cylinder_mesh(15.0, 20.0, 16),
+            create_3d_polygon_mesh(&get_all_r_parts()[2].points, 20.0),
+        ];
+        for mesh in &meshes {
+            let attribute = |id| match mesh.attribute(id) {
+                Some(VertexAttributeValues::Float32x3(v)) => v.iter().map(|a| Vec3::from_array(*a).extend(0.0)).collect(),
+                Some(VertexAttributeValues::Float32x2(v)) => v.iter().map(|a| Vec4::new(a[0], a[1], 0.0, 0.0)).collect(),
+                Some(VertexAttributeValues::Float32x4(v)) => v.iter().map(|a| Vec4::from_array(*a)).collect(),
+                _ => Vec::new(),
+            };
+            let (normals, uvs, tangents): (Vec<Vec4>, Vec<Vec4>, Vec<Vec4>) =
+                (attribute(Mesh::ATTRIBUTE_NORMAL), attribute(Mesh::ATTRIBUTE_UV_0), attribute(Mesh::ATTRIBUTE_TANGENT));
+            let positions = mesh_positions(mesh);
+            assert_eq!(tangents.len(), positions.len());
+
+            for face in mesh_indices(mesh).chunks_exact(3) {
+                let [a, b, c] = [face[0], face[1], face[2]].map(|i| i as usize);
+                let (e1, e2) = (positions[b] - positions[a], positions[c] - positions[a]);
+                let (d1, d2) = ((uvs[b] - uvs[a]).truncate(), (uvs[c] - uvs[a]).truncate());
+                let det = d1.x * d2.y - d2.x * d1.y;
+                assert!(det.abs() > 1e-9, "UV dégénérés");
+                // Directions de U et de V croissants sur la face
+                let du = (e1 * d2.y - e2 * d1.y) / det;
+                let dv = (e2 * d1.x - e1 * d2.x) / det;
+                for i in [a, b, c] {
+                    let (normal, tangent) = (normals[i].truncate(), tangents[i].truncate());
+                    assert!(tangent.dot(normal).abs() < 1e-3);
+                    assert!(tangent.dot(du) > 0.0);
+                    // Bitangente w·(N × T) vers V décroissant : le haut de l'image (+Y des normal maps)
+                    assert!(normal.cross(tangent).dot(dv) * tangents[i].w < 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn triangulation_skips_collinear_points() {
+        // Carré avec un point au milieu d'un côté, parcouru dans le sens direct
+        let square = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(5.0, 0.0),
+            Vec2::new(10.0, 0.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(0.0, 10.0),
+        ];
+        let triangles = triangulate_polygon(&square);
+        assert_eq!(triangles.len(), 2);
+        assert!(triangles.iter().all(|t| !t.contains(&1)));
+        let area: f32 = triangles
+            .iter()
+            .map(|&[a, b, c]| (square[b] - square[a]).perp_dot(square[c] - square[a]) / 2.0)
+            .sum();
+        assert!((area - 100.0).abs() < 1e-3);
+        assert_eq!(remove_collinear_points(&square).len(), 4);
+    }
+
+    fn square() -> Vec<Vec2> {
+        vec![Vec2::new(0.0, 0.0), Vec2::new(10.0, 0.0), Vec2::new(10.0, 10.0), Vec2::new(0.0, 10.0)]
+    }
+
+    #[test]
+    fn resample_keeps_count_and_corners() {
+        let points = resample_polygon(&square(), 8);
+        assert_eq!(points.len(), 8);
+        // Premier point conservé, coins retrouvés tous les deux points
+        for (i, corner) in square().iter().enumerate() {
+            assert!(points[2 * i].distance(*corner) < 1e-4);
+        }
+        assert!(points[1].distance(Vec2::new(5.0, 0.0)) < 1e-4);
+
+        assert_eq!(resample_polygon(&square(), 13).len(), 13);
+        assert!(resample_polygon(&[], 5).is_empty());
+        assert_eq!(polygon_signed_area(&square()), 100.0);
+    }
+
+    #[test]
+    fn match_outlines_aligns_orientation_and_start() {
+        // Même carré, parcouru dans l'autre sens à partir d'un autre coin
+        let mut other = square();
+        other.rotate_left(2);
+        other.reverse();
+        let (a, b) = match_outlines(&square(), &other, 8);
+        assert_eq!(b.len(), 8);
+        for (p, q) in a.iter().zip(&b) {
+            assert!(p.distance(*q) < 1e-4);
+        }
+    }
+
+    #[test]
+    fn r_and_b_outlines_are_aligned() {
+        let cost = |a: &[Vec2], b: &[Vec2], shift: usize| -> f32 {
+            (0..a.len()).map(|i| a[i].distance_squared(b[(i + shift) % b.len()])).sum()
+        };
+        for (r, b) in get_all_r_parts().iter().zip(get_all_b_parts()) {
+            let (a, b) = match_outlines(&r.points, &b.points, 32);
+            assert_eq!((a.len(), b.len()), (32, 32));
+            assert_eq!(polygon_signed_area(&a).signum(), polygon_signed_area(&b).signum(), "{}", r.name);
+            // Aucun autre décalage ne rapproche davantage les deux contours
+            for shift in 1..32 {
+                assert!(cost(&a, &b, 0) <= cost(&a, &b, shift), "{} : décalage {}", r.name, shift);
+            }
+        }
+    }
+
+    #[test]
+    fn merge_meshes_offsets_indices_and_transforms_normals() {
+        let triangle = |normal: Vec3| build_mesh(
+            vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            vec![normal.normalize().to_array(); 3],
+            vec![[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]],
+            vec![0, 1, 2],
+        );
+        let (flat, sloped) = (triangle(Vec3::Z), triangle(Vec3::new(1.0, 0.0, 1.0)));
+        let transform = Transform::from_xyz(10.0, 0.0, 0.0)
+            .with_rotation(Quat::from_rotation_y(PI / 2.0))
+            .with_scale(Vec3::new(2.0, 1.0, 1.0));
+        let (merged, ranges) = merge_meshes(&[
+            (&flat, Transform::IDENTITY, LinearRgba::RED),
+            (&sloped, transform, LinearRgba::BLUE),
+        ]);
+
+        assert_eq!((ranges[0].vertices.clone(), ranges[0].indices.clone()), (0..3, 0..3));
+        assert_eq!((ranges[1].vertices.clone(), ranges[1].indices.clone()), (3..6, 3..6));
+        assert_eq!(mesh_indices(&merged), vec![0, 1, 2, 3, 4, 5]);
+
+        let positions = mesh_positions(&merged);
+        assert!(positions[4].distance(Vec3::new(10.0, 0.0, -2.0)) < 1e-4);
+        let Some(VertexAttributeValues::Float32x3(normals)) = merged.attribute(Mesh::ATTRIBUTE_NORMAL) else {
+            panic!("normales absentes");
+        };
+        assert!(Vec3::from_array(normals[0]).distance(Vec3::Z) < 1e-4);
+        // Échelle non uniforme : la normale suit l'inverse transposée, pas la matrice
+        let expected = Vec3::new(1.0, 0.0, -0.5).normalize();
+        assert!(Vec3::from_array(normals[3]).distance(expected) < 1e-4);
+        let Some(VertexAttributeValues::Float32x4(colors)) = merged.attribute(Mesh::ATTRIBUTE_COLOR) else {
+            panic!("couleurs absentes");
+        };
+        assert_eq!((colors[2], colors[3]), (LinearRgba::RED.to_f32_array(), LinearRgba::BLUE.to_f32_array()));
+    }
+
+    #[test]
+    fn r_part_names_follow_the_definitions() {
+        let names: Vec<&str> = get_all_r_parts().iter().map(|p| p.name).collect();
+        assert_eq!(names, R_PART_NAMES);
+    }
+}
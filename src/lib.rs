@@ -8,16 +8,94 @@ use bevy::prelude::*;
 pub mod config;
 pub mod materials;
 pub mod geometry;
+pub mod picking;
+pub mod timeline;
+pub mod spec;
+pub mod file_io;
+pub mod audio;
+pub mod physics;
+pub mod palette;
+pub mod cvd;
+pub mod cache;
+pub mod mesh_diagnostics;
 pub mod systems;
 
+use spec::LogoSpec;
+use palette::Palette;
+use cache::{MaterialCache, MeshCache};
 use systems::setup::setup_system;
 use systems::camera::{camera_control_system, rotate_object_system};  // MODIFIÉ
+use systems::framing::{camera_framing_system, FrameLogoRequest, SelectedPart};
+use systems::camera_path::{camera_path_system, CameraPathPlayer};
+use systems::fly_camera::fly_camera_system;
+use systems::viewports::{viewport_layout_system, ViewportLayout};
+use systems::picking::{hover_highlight_system, picking_system, HoveredPart};
+use systems::part_motion::{init_rest_transform_system, reset_part_transforms_system, PartMotionSet};
+use systems::explode::{explode_system, init_explode_direction_system, ExplodeState};
+use systems::timeline::{timeline_clock_system, timeline_material_system, timeline_transform_system, TimelinePlayer};
+use systems::intro::{intro_system, IntroState};
+use systems::material_animation::{material_animation_system, MaterialAnimation};
+use systems::morph::{morph_system, MorphState};
+use systems::audio_reactive::{audio_playback_system, audio_reactive_system, AudioReactive};
+use systems::spring::{init_spring_system, spring_apply_system, spring_integration_system, wobble_trigger_system};
+use systems::shatter::{shatter_apply_system, shatter_control_system, shatter_integration_system, ShatterState};
+use systems::particles::{init_particle_emitters_system, particle_emit_system, particle_update_system, setup_particle_pool_system};
+use systems::lod::{init_lod_system, lod_system};
+use systems::logo_wall::{logo_wall_animation_system, logo_wall_toggle_system};
+use systems::bake::{bake_system, baked_highlight_system};
+use systems::material_presets::material_preset_system;
+use systems::palette::{load_theme_file_system, palette_system, CvdSimulation};
 
 
 pub fn run() {
     App::new()
         .add_plugins(DefaultPlugins)
-        .add_systems(Startup, setup_system)
+        .insert_resource(Time::<Fixed>::from_hz(config::FIXED_UPDATE_HZ))
+        .init_resource::<SelectedPart>()
+        .init_resource::<CameraPathPlayer>()
+        .init_resource::<ViewportLayout>()
+        .init_resource::<HoveredPart>()
+        .init_resource::<ExplodeState>()
+        .init_resource::<TimelinePlayer>()
+        .init_resource::<IntroState>()
+        .init_resource::<LogoSpec>()
+        .init_resource::<MaterialAnimation>()
+        .init_resource::<MorphState>()
+        .init_resource::<AudioReactive>()
+        .init_resource::<ShatterState>()
+        .init_resource::<Palette>()
+        .init_resource::<CvdSimulation>()
+        .init_resource::<MaterialCache>()
+        .init_resource::<MeshCache>()
+        .add_event::<FrameLogoRequest>()
+        .add_systems(Startup, (setup_system, setup_particle_pool_system, load_theme_file_system))
         .add_systems(Update, (camera_control_system,rotate_object_system,))  // NOUVEAU : contrôle souris
+        .add_systems(Update, camera_framing_system.before(camera_control_system))
+        .add_systems(Update, camera_path_system.before(camera_control_system))
+        .add_systems(Update, fly_camera_system.before(camera_control_system))
+        .add_systems(Update, viewport_layout_system.before(camera_framing_system))
+        .add_systems(Update, (picking_system, hover_highlight_system).chain())
+        .configure_sets(Update, (PartMotionSet::Reset, PartMotionSet::Animate, PartMotionSet::Override).chain())
+        .add_systems(Update, (init_rest_transform_system, reset_part_transforms_system).in_set(PartMotionSet::Reset))
+        .add_systems(Update, (init_explode_direction_system, explode_system).in_set(PartMotionSet::Animate))
+        .add_systems(Update, timeline_clock_system.before(PartMotionSet::Animate))
+        .add_systems(Update, (timeline_transform_system.before(explode_system), timeline_material_system.after(hover_highlight_system)).in_set(PartMotionSet::Animate))
+        .add_systems(Update, intro_system.in_set(PartMotionSet::Animate))
+        .add_systems(Update, material_animation_system)
+        .add_systems(Update, morph_system.after(intro_system))
+        .add_systems(Update, audio_playback_system.before(PartMotionSet::Animate))
+        .add_systems(Update, audio_reactive_system.in_set(PartMotionSet::Animate))
+        .add_systems(Update, (init_spring_system, wobble_trigger_system.after(picking_system)).before(PartMotionSet::Animate))
+        .add_systems(FixedUpdate, spring_integration_system)
+        .add_systems(Update, spring_apply_system.in_set(PartMotionSet::Animate))
+        .add_systems(Update, shatter_control_system.before(PartMotionSet::Animate))
+        .add_systems(FixedUpdate, shatter_integration_system)
+        .add_systems(Update, shatter_apply_system.in_set(PartMotionSet::Override))
+        .add_systems(Update, (material_preset_system, palette_system).chain().after(hover_highlight_system))
+        .add_systems(Update, bake_system.before(picking_system))
+        .add_systems(Update, (logo_wall_toggle_system, logo_wall_animation_system).chain())
+        .add_systems(Update, (init_lod_system, lod_system).chain().before(intro_system).before(picking_system))
+        .add_systems(Update, baked_highlight_system.after(picking_system))
+        .add_systems(Update, (init_particle_emitters_system, particle_emit_system, particle_update_system).chain())
         .run();
 }
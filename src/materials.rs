@@ -4,6 +4,8 @@
 // ╚══════════════════════════════════════════════════════════════════════════╝
 
 use bevy::prelude::*;
+use bevy::image::ImageLoaderSettings;
+use crate::systems::setup::LogoPart;
 
 /// Matériau 3D pour le cercle principal
 pub fn get_main_circle_material() -> StandardMaterial {
@@ -63,3 +65,166 @@ pub fn get_r_logo_material() -> StandardMaterial {
         ..default()
     }
 }
+
+/// Matériau d'origine d'une partie du logo
+pub fn get_part_material(part: &LogoPart) -> StandardMaterial {
+    match part {
+        LogoPart::Ring => get_main_circle_material(),
+        LogoPart::ExteriorTriangle(i) => get_rainbow_material(*i),
+        LogoPart::InteriorTriangle(i) => get_interior_triangle_material(*i),
+        LogoPart::SmallCircle(_) => get_small_circle_material(),
+        LogoPart::RPart(_) => get_r_logo_material(),
+    }
+}
+
+// === BIBLIOTHÈQUE DE PRÉRÉGLAGES PBR ===
+
+/// Chemins (relatifs au dossier `assets`) des textures d'un préréglage
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PresetTextures {
+    pub base_color: Option<String>,
+    pub normal_map: Option<String>,
+    /// Texture métal (canal B) / rugosité (canal G), convention glTF
+    pub metallic_roughness: Option<String>,
+    pub emissive: Option<String>,
+}
+
+impl PresetTextures {
+    /// Textures de `self`, complétées par celles de `fallback`
+    pub fn or(&self, fallback: &PresetTextures) -> PresetTextures {
+        let pick = |own: &Option<String>, other: &Option<String>| own.clone().or_else(|| other.clone());
+        PresetTextures {
+            base_color: pick(&self.base_color, &fallback.base_color),
+            normal_map: pick(&self.normal_map, &fallback.normal_map),
+            metallic_roughness: pick(&self.metallic_roughness, &fallback.metallic_roughness),
+            emissive: pick(&self.emissive, &fallback.emissive),
+        }
+    }
+}
+
+/// Préréglage de matériau PBR
+#[derive(Clone, Debug, PartialEq)]
+pub struct MaterialPreset {
+    pub name: String,
+    /// Couleur propre au matériau ; `None` : couleur de la partie (palette)
+    pub base_color: Option<Color>,
+    pub metallic: f32,
+    pub perceptual_roughness: f32,
+    pub reflectance: f32,
+    /// Vernis transparent au-dessus de la couche de base
+    pub clearcoat: f32,
+    /// Transmission de la lumière (verre)
+    pub specular_transmission: f32,
+    /// Mode de transparence imposé ; `None` : celui du matériau d'origine
+    /// (les petits cercles restent mélangés pour leur fondu d'opacité)
+    pub alpha_mode: Option<AlphaMode>,
+    pub textures: PresetTextures,
+}
+
+impl MaterialPreset {
+    fn new(name: &str, base_color: Option<Color>, metallic: f32, perceptual_roughness: f32) -> Self {
+        Self {
+            name: name.to_string(),
+            base_color,
+            metallic,
+            perceptual_roughness,
+            reflectance: 0.5,
+            clearcoat: 0.0,
+            specular_transmission: 0.0,
+            alpha_mode: None,
+            textures: PresetTextures::default(),
+        }
+    }
+
+    /// Applique le préréglage à un matériau existant (la couleur est gardée
+    /// si le préréglage n'en impose pas)
+    pub fn apply(&self, material: &mut StandardMaterial, asset_server: &AssetServer) {
+        if let Some(color) = self.base_color {
+            material.base_color = color;
+        }
+        material.metallic = self.metallic;
+        material.perceptual_roughness = self.perceptual_roughness;
+        material.reflectance = self.reflectance;
+        material.clearcoat = self.clearcoat;
+        material.specular_transmission = self.specular_transmission;
+        if let Some(alpha_mode) = self.alpha_mode {
+            material.alpha_mode = alpha_mode;
+        }
+
+        // Les textures de couleur sont en sRGB ; normales et métal-rugosité sont linéaires
+        let load = |path: &Option<String>| path.as_ref().map(|path| asset_server.load(path.clone()));
+        let load_linear = |path: &Option<String>| {
+            path.as_ref().map(|path| {
+                asset_server.load_with_settings(path.clone(), |settings: &mut ImageLoaderSettings| settings.is_srgb = false)
+            })
+        };
+        material.base_color_texture = load(&self.textures.base_color);
+        material.normal_map_texture = load_linear(&self.textures.normal_map);
+        material.metallic_roughness_texture = load_linear(&self.textures.metallic_roughness);
+        material.emissive_texture = load(&self.textures.emissive);
+    }
+}
+
+/// Préréglages intégrés
+pub fn material_presets() -> Vec<MaterialPreset> {
+    vec![
+        MaterialPreset {
+            reflectance: 0.6,
+            ..MaterialPreset::new("acier brossé", Some(Color::srgb(0.62, 0.64, 0.66)), 1.0, 0.45)
+        },
+        MaterialPreset::new("cuivre", Some(Color::srgb(0.95, 0.64, 0.54)), 1.0, 0.3),
+        MaterialPreset::new("or", Some(Color::srgb(1.0, 0.78, 0.34)), 1.0, 0.2),
+        MaterialPreset {
+            clearcoat: 1.0,
+            ..MaterialPreset::new("émail", None, 0.0, 0.35)
+        },
+        MaterialPreset {
+            specular_transmission: 0.9,
+            ..MaterialPreset::new("verre", None, 0.0, 0.05)
+        },
+        MaterialPreset {
+            reflectance: 0.3,
+            ..MaterialPreset::new("plastique mat", None, 0.0, 0.9)
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::AssetPlugin;
+
+    #[test]
+    fn apply_keeps_what_the_preset_leaves_open() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default())).init_asset::<Image>();
+        let asset_server = app.world().resource::<AssetServer>();
+        let presets = material_presets();
+        let preset = |name: &str| presets.iter().find(|p| p.name == name).unwrap();
+
+        // Émail : couleur et transparence des petits cercles conservées
+        let mut circle = get_small_circle_material();
+        preset("émail").apply(&mut circle, asset_server);
+        assert_eq!(circle.base_color, get_small_circle_material().base_color);
+        assert_eq!(circle.alpha_mode, AlphaMode::Blend);
+        assert_eq!(circle.clearcoat, 1.0);
+
+        // Or : couleur imposée, mode de transparence imposé sur demande
+        let mut ring = get_main_circle_material();
+        let gold = MaterialPreset { alpha_mode: Some(AlphaMode::Mask(0.5)), ..preset("or").clone() };
+        gold.apply(&mut ring, asset_server);
+        assert_eq!(ring.base_color, Color::srgb(1.0, 0.78, 0.34));
+        assert_eq!((ring.metallic, ring.perceptual_roughness), (1.0, 0.2));
+        assert_eq!(ring.alpha_mode, AlphaMode::Mask(0.5));
+        assert!(ring.normal_map_texture.is_none());
+
+        // Textures chargées depuis le dossier assets
+        let textured = MaterialPreset {
+            textures: PresetTextures { normal_map: Some("textures/acier_brosse_normal.png".to_string()), ..default() },
+            ..preset("acier brossé").clone()
+        };
+        textured.apply(&mut ring, asset_server);
+        assert!(ring.normal_map_texture.is_some());
+        assert!(ring.base_color_texture.is_none());
+    }
+}
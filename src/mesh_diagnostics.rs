@@ -0,0 +1,302 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                   FICHIER: src/mesh_diagnostics.rs                       ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Diagnostic de la qualité des meshes
+//!
+//! Les sommets de même position sont d'abord soudés : un mesh dont les faces
+//! ont leurs propres sommets (normales franches) reste ainsi un solide
+//! fermé. Le rapport compte :
+//! - Arêtes ouvertes (bordées d'un seul triangle)
+//! - Arêtes non-manifold (bordées de plus de deux triangles)
+//! - Arêtes d'enroulement incohérent (parcourues deux fois dans le même sens)
+//! - Normales inversées (normale de sommet opposée à l'orientation de la face)
+//! - Triangles d'aire nulle
+//! - Sommets en double (même position, même normale et mêmes UV : les
+//!   coutures de texture ne sont pas des doublons)
+//! - Volume signé (négatif : solide retourné)
+//! - Indices hors limites et attributs de longueur incohérente : les faces
+//!   concernées sont ignorées au lieu de faire échouer l'analyse
+
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+use std::collections::{HashMap, HashSet};
+use crate::geometry;
+
+/// Pas de la grille de soudure des positions
+const WELD_TOLERANCE: f32 = 1e-4;
+
+/// Aire en dessous de laquelle un triangle est dégénéré
+const MIN_TRIANGLE_AREA: f32 = 1e-6;
+
+/// Rapport de diagnostic d'un mesh
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshReport {
+    pub vertex_count: usize,
+    pub triangle_count: usize,
+    pub open_edges: usize,
+    pub non_manifold_edges: usize,
+    pub inconsistent_winding_edges: usize,
+    pub inverted_normal_triangles: usize,
+    pub zero_area_triangles: usize,
+    pub duplicate_vertices: usize,
+    /// Triangles dont un indice dépasse le nombre de sommets
+    pub invalid_indices: usize,
+    /// Attributs (normales, UV) dont la longueur diffère du nombre de positions
+    pub attribute_length_mismatches: usize,
+    /// Volume signé (positif si les faces sont orientées vers l'extérieur)
+    pub signed_volume: f32,
+}
+
+impl MeshReport {
+    /// Solide fermé, cohérent et orienté vers l'extérieur
+    pub fn is_clean(&self) -> bool {
+        self.open_edges == 0
+            && self.non_manifold_edges == 0
+            && self.inconsistent_winding_edges == 0
+            && self.inverted_normal_triangles == 0
+            && self.zero_area_triangles == 0
+            && self.duplicate_vertices == 0
+            && self.invalid_indices == 0
+            && self.attribute_length_mismatches == 0
+            && self.signed_volume > 0.0
+    }
+}
+
+impl std::fmt::Display for MeshReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} sommets, {} triangles : {} arêtes ouvertes, {} non-manifold, {} d'enroulement incohérent, \
+             {} normales inversées, {} triangles dégénérés, {} sommets en double, {} triangles aux indices invalides, \
+             {} attributs de longueur incohérente, volume {:.1}",
+            self.vertex_count,
+            self.triangle_count,
+            self.open_edges,
+            self.non_manifold_edges,
+            self.inconsistent_winding_edges,
+            self.inverted_normal_triangles,
+            self.zero_area_triangles,
+            self.duplicate_vertices,
+            self.invalid_indices,
+            self.attribute_length_mismatches,
+            self.signed_volume,
+        )
+    }
+}
+
+/// Position arrondie sur la grille de soudure
+fn weld_key(v: Vec3) -> [i64; 3] {
+    (v / WELD_TOLERANCE).round().as_i64vec3().to_array()
+}
+
+/// Analyse un mesh (positions, normales et UV éventuels, indices)
+pub fn analyze_mesh(mesh: &Mesh) -> MeshReport {
+    let normals: Option<Vec<Vec3>> = match mesh.attribute(Mesh::ATTRIBUTE_NORMAL) {
+        Some(VertexAttributeValues::Float32x3(values)) => Some(values.iter().map(|n| Vec3::from_array(*n)).collect()),
+        _ => None,
+    };
+    let uvs: Option<Vec<Vec2>> = match mesh.attribute(Mesh::ATTRIBUTE_UV_0) {
+        Some(VertexAttributeValues::Float32x2(values)) => Some(values.iter().map(|uv| Vec2::from_array(*uv)).collect()),
+        _ => None,
+    };
+    analyze(&geometry::mesh_positions(mesh), normals.as_deref(), uvs.as_deref(), &geometry::mesh_indices(mesh))
+}
+
+/// Analyse une liste de triangles indexés
+pub fn analyze(positions: &[Vec3], normals: Option<&[Vec3]>, uvs: Option<&[Vec2]>, indices: &[u32]) -> MeshReport {
+    let mut report = MeshReport {
+        vertex_count: positions.len(),
+        triangle_count: indices.len() / 3,
+        attribute_length_mismatches: [normals.map(<[Vec3]>::len), uvs.map(<[Vec2]>::len)]
+            .into_iter()
+            .flatten()
+            .filter(|&len| len != positions.len())
+            .count(),
+        ..default()
+    };
+
+    // === SOUDURE DES POSITIONS ET SOMMETS EN DOUBLE ===
+    let mut welded_ids: HashMap<[i64; 3], u32> = HashMap::new();
+    let welded: Vec<u32> = positions
+        .iter()
+        .map(|p| {
+            let next = welded_ids.len() as u32;
+            *welded_ids.entry(weld_key(*p)).or_insert(next)
+        })
+        .collect();
+
+    let mut seen: HashSet<[[i64; 3]; 3]> = HashSet::new();
+    for (i, position) in positions.iter().enumerate() {
+        let normal = normals.and_then(|n| n.get(i)).map_or([0; 3], |n| weld_key(*n));
+        let uv = uvs.and_then(|uv| uv.get(i)).map_or([0; 3], |uv| weld_key(uv.extend(0.0)));
+        if !seen.insert([weld_key(*position), normal, uv]) {
+            report.duplicate_vertices += 1;
+        }
+    }
+
+    // === TRIANGLES ===
+    // Arête non orientée -> (nombre de triangles, somme des sens de parcours)
+    let mut edges: HashMap<(u32, u32), (usize, i32)> = HashMap::new();
+    for face in indices.chunks_exact(3) {
+        let (Some(&a), Some(&b), Some(&c)) =
+            (positions.get(face[0] as usize), positions.get(face[1] as usize), positions.get(face[2] as usize))
+        else {
+            report.invalid_indices += 1;
+            continue;
+        };
+        let face_normal = (b - a).cross(c - a);
+        report.signed_volume += a.dot(b.cross(c)) / 6.0;
+
+        if face_normal.length() / 2.0 < MIN_TRIANGLE_AREA {
+            report.zero_area_triangles += 1;
+            continue;
+        }
+        if let Some(normals) = normals
+            && face.iter().any(|&i| normals.get(i as usize).is_some_and(|n| n.dot(face_normal) < 0.0))
+        {
+            report.inverted_normal_triangles += 1;
+        }
+
+        for k in 0..3 {
+            let (from, to) = (welded[face[k] as usize], welded[face[(k + 1) % 3] as usize]);
+            if from == to {
+                continue;
+            }
+            let (key, direction) = if from < to { ((from, to), 1) } else { ((to, from), -1) };
+            let entry = edges.entry(key).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 += direction;
+        }
+    }
+
+    // === ARÊTES ===
+    for (count, direction_sum) in edges.values() {
+        match count {
+            1 => report.open_edges += 1,
+            2 if *direction_sum != 0 => report.inconsistent_winding_edges += 1,
+            2 => {}
+            _ => report.non_manifold_edges += 1,
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+    use crate::systems::setup::{logo_parts, PartShape};
+
+    fn assert_clean(name: &str, mesh: &Mesh) {
+        let report = analyze_mesh(mesh);
+        assert!(report.is_clean(), "{name} : {report}");
+    }
+
+    #[test]
+    fn ring_meshes_are_clean() {
+        let (outer, inner) = (config::CIRCLE_RADIUS, config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS);
+        for segments in [3, 16, config::CIRCLE_SEGMENTS] {
+            assert_clean("anneau", &geometry::create_3d_ring_mesh(outer, inner, config::DEPTH, segments));
+        }
+        for arc in [0.1, 1.0, std::f32::consts::PI, 6.0] {
+            let mesh = geometry::create_3d_ring_arc_mesh(outer, inner, config::DEPTH, config::CIRCLE_SEGMENTS, arc);
+            assert_clean("arc d'anneau", &mesh);
+        }
+    }
+
+    #[test]
+    fn cylinder_meshes_are_clean() {
+        for segments in [3, 8, config::SMALL_CIRCLE_SEGMENTS] {
+            let mesh = geometry::create_3d_cylinder_mesh(config::SMALL_CIRCLE_RADIUS, config::DEPTH, segments);
+            assert_clean("cylindre", &mesh);
+        }
+        let mesh = geometry::create_3d_cylinder_mesh_with(
+            config::SMALL_CIRCLE_RADIUS,
+            config::DEPTH,
+            geometry::Tessellation::ChordError(0.05),
+        );
+        assert_clean("cylindre adaptatif", &mesh);
+    }
+
+    #[test]
+    fn logo_parts_are_clean() {
+        for spec in logo_parts() {
+            let name = spec.part.to_string();
+            assert_clean(&name, &spec.shape.mesh(config::CIRCLE_SEGMENTS, config::SMALL_CIRCLE_SEGMENTS));
+            // Sens indirect : le constructeur réoriente le contour
+            if let PartShape::Triangle(p1, p2, p3) = spec.shape {
+                assert_clean(&name, &PartShape::Triangle(p1, p3, p2).mesh(0, 0));
+            }
+        }
+    }
+
+    #[test]
+    fn merged_logo_is_clean() {
+        // Les parties du R se touchent le long de leurs contours : elles sont
+        // écartées en profondeur pour que chaque solide reste distinct
+        let parts: Vec<_> = logo_parts()
+            .into_iter()
+            .enumerate()
+            .map(|(i, spec)| {
+                let transform = spec.transform.with_translation(spec.transform.translation + Vec3::Z * 100.0 * i as f32);
+                (spec.shape.mesh(config::CIRCLE_SEGMENTS, config::SMALL_CIRCLE_SEGMENTS), transform)
+            })
+            .collect();
+        let inputs: Vec<_> = parts.iter().map(|(mesh, transform)| (mesh, *transform, LinearRgba::WHITE)).collect();
+        let (merged, _) = geometry::merge_meshes(&inputs);
+        let report = analyze_mesh(&merged);
+        assert!(report.is_clean(), "logo fusionné : {report}");
+        let triangles: usize = parts.iter().map(|(mesh, _)| analyze_mesh(mesh).triangle_count).sum();
+        assert_eq!(report.triangle_count, triangles);
+    }
+
+    #[test]
+    fn polygon_meshes_are_clean() {
+        for part in geometry::get_all_r_parts().into_iter().chain(geometry::get_all_b_parts()) {
+            assert_clean(part.name, &geometry::create_3d_polygon_mesh(&part.points, config::DEPTH));
+        }
+        // Contour concave (forme en L)
+        let l_shape = [
+            Vec2::new(0.0, 0.0),
+            Vec2::new(40.0, 0.0),
+            Vec2::new(40.0, 10.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(10.0, 40.0),
+            Vec2::new(0.0, 40.0),
+        ];
+        assert_clean("forme en L", &geometry::create_3d_polygon_mesh(&l_shape, config::DEPTH));
+    }
+
+    #[test]
+    fn defects_are_reported() {
+        let positions = [Vec3::ZERO, Vec3::X, Vec3::Y, Vec3::Z, Vec3::X];
+        let normals = [Vec3::NEG_Z; 5];
+
+        // Triangle seul retourné : arêtes ouvertes et normales inversées
+        let report = analyze(&positions, Some(&normals), None, &[0, 2, 1]);
+        assert_eq!(report.open_edges, 3);
+        assert_eq!(report.inverted_normal_triangles, 0);
+        let report = analyze(&positions, Some(&normals), None, &[0, 1, 2]);
+        assert_eq!(report.inverted_normal_triangles, 1);
+
+        // Deux triangles parcourant l'arête commune dans le même sens
+        let report = analyze(&positions, None, None, &[0, 1, 2, 0, 1, 3]);
+        assert_eq!(report.inconsistent_winding_edges, 1);
+
+        // Trois triangles sur la même arête
+        let report = analyze(&positions, None, None, &[0, 1, 2, 1, 0, 3, 0, 1, 3]);
+        assert_eq!(report.non_manifold_edges, 1);
+
+        // Triangle plat et sommet en double
+        let report = analyze(&positions, Some(&normals), None, &[0, 1, 4]);
+        assert_eq!(report.zero_area_triangles, 1);
+        assert_eq!(report.duplicate_vertices, 1);
+
+        // Indice hors limites et attributs trop courts : signalés, sans panique
+        let report = analyze(&positions, Some(&normals[..2]), Some(&[Vec2::ZERO]), &[0, 1, 9, 0, 1, 2]);
+        assert_eq!(report.invalid_indices, 1);
+        assert_eq!(report.attribute_length_mismatches, 2);
+        assert!(!report.is_clean());
+    }
+}
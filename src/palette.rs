@@ -0,0 +1,274 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/palette.rs                           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Palettes de couleurs du logo (thèmes)
+//!
+//! Format texte (une commande par ligne, `#` pour les commentaires, les
+//! couleurs en hexadécimal `RRGGBB` ou `RRGGBBAA`) :
+//!
+//! ```text
+//! theme <nom>
+//! background <couleur>
+//! ring <couleur>
+//! exterior rainbow <saturation> <luminosité>
+//! exterior <couleur> <couleur>...
+//! interior <couleur>...
+//! circle <couleur>
+//! r <couleur>
+//! ```
+//!
+//! `exterior` accepte soit un arc-en-ciel, soit un dégradé parcouru autour
+//! de l'anneau. Les commandes non précisées reprennent le thème classique.
+
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::config;
+use crate::file_io::line_error;
+use crate::systems::setup::LogoPart;
+
+/// Couleurs des triangles extérieurs
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExteriorColors {
+    /// Teinte qui fait le tour du cercle chromatique
+    Rainbow { saturation: f32, lightness: f32 },
+    /// Dégradé aller-retour entre plusieurs couleurs
+    Gradient(Vec<Color>),
+}
+
+impl ExteriorColors {
+    /// Couleur du triangle `index`
+    pub fn color(&self, index: usize) -> Color {
+        let count = config::EXTERIOR_TRIANGLES_COUNT.max(1);
+        match self {
+            ExteriorColors::Rainbow { saturation, lightness } => {
+                Color::hsl(index as f32 * 360.0 / count as f32, *saturation, *lightness)
+            }
+            ExteriorColors::Gradient(colors) => {
+                let Some(first) = colors.first() else { return Color::WHITE };
+                if colors.len() == 1 {
+                    return *first;
+                }
+                // Aller-retour : le dernier triangle rejoint le premier sans saut
+                let u = index as f32 / count as f32;
+                let u = 1.0 - (2.0 * u - 1.0).abs();
+                let position = u * (colors.len() - 1) as f32;
+                let i = (position.floor() as usize).min(colors.len() - 2);
+                colors[i].mix(&colors[i + 1], position - i as f32)
+            }
+        }
+    }
+}
+
+/// Thème nommé : une couleur par catégorie de partie
+#[derive(Clone, Debug, PartialEq)]
+pub struct Theme {
+    pub name: String,
+    /// Couleur de fond de la scène
+    pub background: Color,
+    pub ring: Color,
+    pub exterior: ExteriorColors,
+    /// Couleurs des triangles intérieurs (parcourues en boucle)
+    pub interior: Vec<Color>,
+    pub small_circle: Color,
+    pub r: Color,
+}
+
+impl Theme {
+    /// Couleur de base d'une partie dans ce thème
+    pub fn part_color(&self, part: &LogoPart) -> Color {
+        match part {
+            LogoPart::Ring => self.ring,
+            LogoPart::ExteriorTriangle(i) => self.exterior.color(*i),
+            LogoPart::InteriorTriangle(i) => self.interior[i % self.interior.len()],
+            LogoPart::SmallCircle(_) => self.small_circle,
+            LogoPart::RPart(_) => self.r,
+        }
+    }
+
+    /// Thème d'origine (couleurs de `materials.rs`)
+    pub fn classic() -> Self {
+        Self {
+            name: "classique".to_string(),
+            background: Color::srgb_u8(43, 44, 47),
+            ring: Color::srgb(0.8, 0.2, 0.1),
+            exterior: ExteriorColors::Rainbow { saturation: 0.8, lightness: 0.6 },
+            interior: vec![
+                Color::srgb(0.2, 0.6, 0.9),
+                Color::srgb(0.9, 0.6, 0.2),
+                Color::srgb(0.2, 0.9, 0.6),
+                Color::srgb(0.9, 0.2, 0.6),
+                Color::srgb(0.6, 0.2, 0.9),
+            ],
+            small_circle: Color::srgba(1.0, 1.0, 1.0, 0.9),
+            r: Color::srgb(1.0, 0.5, 0.0),
+        }
+    }
+
+    /// Thèmes intégrés
+    pub fn builtin() -> Vec<Self> {
+        let classic = Self::classic();
+        vec![
+            Self {
+                name: "orange".to_string(),
+                ring: Color::srgb(0.81, 0.26, 0.17),
+                exterior: ExteriorColors::Gradient(vec![Color::srgb(0.97, 0.3, 0.0), Color::srgb(0.87, 0.65, 0.52)]),
+                interior: vec![Color::srgb(0.72, 0.25, 0.05), Color::srgb(0.95, 0.55, 0.2)],
+                r: Color::srgb(0.97, 0.3, 0.0),
+                ..classic.clone()
+            },
+            Self {
+                name: "monochrome".to_string(),
+                ring: Color::srgb(0.3, 0.3, 0.3),
+                exterior: ExteriorColors::Rainbow { saturation: 0.0, lightness: 0.7 },
+                interior: vec![Color::srgb(0.5, 0.5, 0.5), Color::srgb(0.65, 0.65, 0.65)],
+                small_circle: Color::srgba(0.95, 0.95, 0.95, 0.9),
+                r: Color::srgb(0.1, 0.1, 0.1),
+                ..classic.clone()
+            },
+            Self {
+                name: "sombre".to_string(),
+                background: Color::srgb(0.05, 0.05, 0.07),
+                ring: Color::srgb(0.45, 0.12, 0.08),
+                exterior: ExteriorColors::Rainbow { saturation: 0.5, lightness: 0.35 },
+                interior: vec![
+                    Color::srgb(0.12, 0.3, 0.45),
+                    Color::srgb(0.45, 0.3, 0.12),
+                    Color::srgb(0.12, 0.45, 0.3),
+                    Color::srgb(0.45, 0.12, 0.3),
+                    Color::srgb(0.3, 0.12, 0.45),
+                ],
+                small_circle: Color::srgba(0.7, 0.7, 0.75, 0.8),
+                r: Color::srgb(0.85, 0.45, 0.1),
+            },
+            Self {
+                name: "marque".to_string(),
+                background: Color::srgb(0.96, 0.94, 0.91),
+                ring: Color::srgb(0.0, 0.0, 0.0),
+                exterior: ExteriorColors::Gradient(vec![Color::srgb(0.0, 0.0, 0.0), Color::srgb(0.35, 0.35, 0.35)]),
+                interior: vec![Color::srgb(0.81, 0.26, 0.17), Color::srgb(0.87, 0.65, 0.52)],
+                small_circle: Color::srgba(0.96, 0.94, 0.91, 1.0),
+                r: Color::srgb(0.81, 0.26, 0.17),
+            },
+            Self {
+                name: "contraste".to_string(),
+                background: Color::BLACK,
+                ring: Color::WHITE,
+                exterior: ExteriorColors::Gradient(vec![Color::srgb(1.0, 1.0, 0.0), Color::WHITE]),
+                interior: vec![Color::srgb(0.0, 0.45, 1.0), Color::srgb(1.0, 0.85, 0.0)],
+                small_circle: Color::BLACK,
+                r: Color::srgb(1.0, 0.85, 0.0),
+            },
+            classic,
+        ]
+        .into_iter()
+        .rev()
+        .collect()
+    }
+}
+
+/// Ressource contenant les thèmes disponibles et le thème actif
+#[derive(Resource, Clone, Debug)]
+pub struct Palette {
+    pub themes: Vec<Theme>,
+    pub current: usize,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self { themes: Theme::builtin(), current: 0 }
+    }
+}
+
+impl Palette {
+    /// Thème actif
+    pub fn theme(&self) -> &Theme {
+        &self.themes[self.current]
+    }
+
+    /// Passe au thème suivant
+    pub fn next(&mut self) {
+        self.current = (self.current + 1) % self.themes.len();
+    }
+
+    /// Ajoute des thèmes ; un thème du même nom est remplacé
+    pub fn merge(&mut self, themes: Vec<Theme>) {
+        for theme in themes {
+            match self.themes.iter_mut().find(|t| t.name == theme.name) {
+                Some(existing) => *existing = theme,
+                None => self.themes.push(theme),
+            }
+        }
+    }
+}
+
+/// Lit des thèmes au format texte
+pub fn themes_from_text(text: &str) -> io::Result<Vec<Theme>> {
+    let mut themes: Vec<Theme> = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let error = |message: &str| line_error(number, message);
+        let color = |text: &str| Srgba::hex(text).map(Color::from).map_err(|_| error("couleur invalide"));
+        let colors = |texts: &[&str]| texts.iter().map(|text| color(text)).collect::<io::Result<Vec<_>>>();
+        let words: Vec<&str> = line.split('#').next().unwrap_or("").split_whitespace().collect();
+
+        if let ["theme", name] = words.as_slice() {
+            themes.push(Theme { name: name.to_string(), ..Theme::classic() });
+            continue;
+        }
+        if words.is_empty() {
+            continue;
+        }
+        let theme = themes.last_mut().ok_or_else(|| error("commande hors thème"))?;
+        match words.as_slice() {
+            ["background", value] => theme.background = color(value)?,
+            ["ring", value] => theme.ring = color(value)?,
+            ["exterior", "rainbow", saturation, lightness] => {
+                theme.exterior = ExteriorColors::Rainbow {
+                    saturation: saturation.parse().map_err(|_| error("saturation invalide"))?,
+                    lightness: lightness.parse().map_err(|_| error("luminosité invalide"))?,
+                };
+            }
+            ["exterior", values @ ..] if !values.is_empty() => theme.exterior = ExteriorColors::Gradient(colors(values)?),
+            ["interior", values @ ..] if !values.is_empty() => theme.interior = colors(values)?,
+            ["circle", value] => theme.small_circle = color(value)?,
+            ["r", value] => theme.r = color(value)?,
+            _ => return Err(error("commande inconnue")),
+        }
+    }
+    Ok(themes)
+}
+
+/// Charge des thèmes depuis un fichier
+pub fn load_themes(path: impl AsRef<Path>) -> io::Result<Vec<Theme>> {
+    themes_from_text(&fs::read_to_string(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_theme_file() {
+        let text = "
+            # Thème d'essai
+            theme essai
+            ring ff0000
+            exterior 000000 ffffff
+            interior 00ff00 0000ff
+        ";
+        let themes = themes_from_text(text).unwrap();
+        assert_eq!(themes.len(), 1);
+        let theme = &themes[0];
+        assert_eq!(theme.name, "essai");
+        assert_eq!(theme.part_color(&LogoPart::Ring), Color::srgb(1.0, 0.0, 0.0));
+        assert_eq!(theme.part_color(&LogoPart::InteriorTriangle(3)), Color::srgb(0.0, 0.0, 1.0));
+        assert_eq!(theme.part_color(&LogoPart::ExteriorTriangle(0)), Color::srgb(0.0, 0.0, 0.0));
+        // Non précisé : repris du thème classique
+        assert_eq!(theme.r, Theme::classic().r);
+
+        assert!(themes_from_text("ring ff0000").is_err());
+        assert!(themes_from_text("theme x\nring rouge").is_err());
+    }
+}
@@ -0,0 +1,173 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/physics.rs                           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Simulation de corps rigides simplifiée et déterministe
+//!
+//! Chaque corps est une boîte orientée qui subit la gravité et rebondit sur
+//! un sol horizontal. Aucune dépendance au moteur : tout se teste sans
+//! fenêtre ni GPU.
+
+use bevy::prelude::*;
+
+/// Corps rigide représenté par sa boîte englobante orientée
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RigidBody {
+    /// Centre de la boîte
+    pub position: Vec3,
+    pub orientation: Quat,
+    pub velocity: Vec3,
+    /// Vitesse angulaire (axe × radians par seconde)
+    pub angular_velocity: Vec3,
+    /// Demi-dimensions de la boîte
+    pub half_extents: Vec3,
+}
+
+/// Paramètres de la simulation
+#[derive(Clone, Copy, Debug)]
+pub struct PhysicsParams {
+    pub gravity: Vec3,
+    /// Hauteur (Y) du sol
+    pub ground_height: f32,
+    /// Coefficient de restitution au rebond (0 : mou, 1 : élastique)
+    pub restitution: f32,
+    /// Part de la vitesse tangentielle perdue à chaque contact
+    pub friction: f32,
+}
+
+impl RigidBody {
+    /// Coins de la boîte dans l'espace de simulation
+    pub fn corners(&self) -> [Vec3; 8] {
+        let h = self.half_extents;
+        let mut corners = [Vec3::ZERO; 8];
+        for (i, corner) in corners.iter_mut().enumerate() {
+            let local = Vec3::new(
+                if i & 1 == 0 { -h.x } else { h.x },
+                if i & 2 == 0 { -h.y } else { h.y },
+                if i & 4 == 0 { -h.z } else { h.z },
+            );
+            *corner = self.position + self.orientation * local;
+        }
+        corners
+    }
+
+    /// Point le plus bas de la boîte
+    pub fn lowest_point(&self) -> f32 {
+        self.corners().iter().map(|c| c.y).fold(f32::INFINITY, f32::min)
+    }
+
+    /// Avance le corps d'un pas `dt` et résout le contact avec le sol
+    pub fn step(&mut self, params: &PhysicsParams, dt: f32) {
+        // === INTÉGRATION (EULER SEMI-IMPLICITE) ===
+        self.velocity += params.gravity * dt;
+        self.position += self.velocity * dt;
+        if self.angular_velocity != Vec3::ZERO {
+            self.orientation = (Quat::from_scaled_axis(self.angular_velocity * dt) * self.orientation).normalize();
+        }
+
+        // === CONTACT AVEC LE SOL ===
+        let penetration = params.ground_height - self.lowest_point();
+        if penetration <= 0.0 {
+            return;
+        }
+        self.position.y += penetration;
+        if self.velocity.y < 0.0 {
+            self.velocity.y = -self.velocity.y * params.restitution;
+        }
+        self.velocity.x *= 1.0 - params.friction;
+        self.velocity.z *= 1.0 - params.friction;
+        self.angular_velocity *= 1.0 - params.friction;
+
+        // Mise au repos : le rebond restant est imperceptible
+        if self.velocity.y.abs() < params.gravity.length() * dt * 2.0 {
+            self.velocity.y = 0.0;
+        }
+        if self.velocity.length_squared() < 1e-2 && self.angular_velocity.length_squared() < 1e-4 {
+            self.velocity = Vec3::ZERO;
+            self.angular_velocity = Vec3::ZERO;
+        }
+    }
+}
+
+/// Générateur pseudo-aléatoire déterministe (xorshift 32 bits)
+#[derive(Clone, Copy, Debug)]
+pub struct DeterministicRng(u32);
+
+impl DeterministicRng {
+    pub fn new(seed: u32) -> Self {
+        // L'état ne doit jamais être nul
+        Self(seed.wrapping_mul(2_654_435_761).max(1))
+    }
+
+    /// Nombre dans [0, 1)
+    pub fn next_f32(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        (self.0 >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Nombre dans [min, max)
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        min + (max - min) * self.next_f32()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: PhysicsParams = PhysicsParams {
+        gravity: Vec3::new(0.0, -600.0, 0.0),
+        ground_height: -100.0,
+        restitution: 0.4,
+        friction: 0.2,
+    };
+
+    fn falling_body() -> RigidBody {
+        RigidBody {
+            position: Vec3::new(0.0, 50.0, 0.0),
+            orientation: Quat::from_rotation_z(0.3),
+            velocity: Vec3::new(80.0, 100.0, 0.0),
+            angular_velocity: Vec3::new(0.0, 0.0, 4.0),
+            half_extents: Vec3::new(20.0, 10.0, 5.0),
+        }
+    }
+
+    fn simulate(mut body: RigidBody, steps: usize) -> RigidBody {
+        for _ in 0..steps {
+            body.step(&PARAMS, 1.0 / 64.0);
+        }
+        body
+    }
+
+    #[test]
+    fn body_settles_on_the_ground() {
+        let body = simulate(falling_body(), 64 * 10);
+        assert!((body.lowest_point() - PARAMS.ground_height).abs() < 1e-2);
+        assert_eq!(body.velocity, Vec3::ZERO);
+        assert_eq!(body.angular_velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn never_sinks_below_the_ground() {
+        let mut body = falling_body();
+        for _ in 0..64 * 5 {
+            body.step(&PARAMS, 1.0 / 64.0);
+            assert!(body.lowest_point() >= PARAMS.ground_height - 1e-3);
+        }
+    }
+
+    #[test]
+    fn simulation_is_deterministic() {
+        assert_eq!(simulate(falling_body(), 300), simulate(falling_body(), 300));
+
+        let mut a = DeterministicRng::new(42);
+        let mut b = DeterministicRng::new(42);
+        for _ in 0..100 {
+            let value = a.next_f32();
+            assert_eq!(value, b.next_f32());
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+}
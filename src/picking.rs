@@ -0,0 +1,163 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/picking.rs                           ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Intersection rayon / mesh côté CPU
+//!
+//! Ces fonctions n'utilisent que les positions et indices des meshes : elles
+//! se testent sans GPU.
+
+use bevy::prelude::*;
+use bevy::render::mesh::VertexAttributeValues;
+
+/// Résultat d'une intersection entre un rayon et un mesh
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MeshHit {
+    /// Distance le long du rayon
+    pub distance: f32,
+    /// Point touché
+    pub point: Vec3,
+    /// Normale de la face touchée, orientée vers l'origine du rayon
+    pub normal: Vec3,
+    /// Indice du triangle touché
+    pub triangle: usize,
+}
+
+/// Intersection rayon / triangle (Möller–Trumbore)
+///
+/// Retourne la distance le long du rayon, les deux faces étant acceptées.
+pub fn ray_triangle_intersection(origin: Vec3, direction: Vec3, a: Vec3, b: Vec3, c: Vec3) -> Option<f32> {
+    let edge1 = b - a;
+    let edge2 = c - a;
+    let p = direction.cross(edge2);
+    let det = edge1.dot(p);
+    if det.abs() < f32::EPSILON {
+        return None;
+    }
+
+    let inv_det = 1.0 / det;
+    let s = origin - a;
+    let u = s.dot(p) * inv_det;
+    if !(0.0..=1.0).contains(&u) {
+        return None;
+    }
+
+    let q = s.cross(edge1);
+    let v = direction.dot(q) * inv_det;
+    if v < 0.0 || u + v > 1.0 {
+        return None;
+    }
+
+    let distance = edge2.dot(q) * inv_det;
+    (distance > 0.0).then_some(distance)
+}
+
+/// Intersection la plus proche entre un rayon et une liste de triangles indexés
+///
+/// Les positions et indices sont lus sur place : aucune copie n'est faite.
+pub fn ray_triangles_intersection(
+    origin: Vec3,
+    direction: Vec3,
+    positions: &[[f32; 3]],
+    indices: impl IntoIterator<Item = usize>,
+) -> Option<MeshHit> {
+    let mut closest: Option<MeshHit> = None;
+    let mut indices = indices.into_iter();
+    let mut triangle = 0;
+
+    while let (Some(i0), Some(i1), Some(i2)) = (indices.next(), indices.next(), indices.next()) {
+        let index = triangle;
+        triangle += 1;
+        // Un indice hors limites (mesh en cours de reconstruction) écarte le triangle
+        let (Some(&a), Some(&b), Some(&c)) = (positions.get(i0), positions.get(i1), positions.get(i2)) else {
+            continue;
+        };
+        let [a, b, c] = [a, b, c].map(Vec3::from_array);
+
+        let Some(distance) = ray_triangle_intersection(origin, direction, a, b, c) else {
+            continue;
+        };
+        if closest.is_some_and(|hit| hit.distance <= distance) {
+            continue;
+        }
+
+        let mut normal = (b - a).cross(c - a).normalize_or_zero();
+        if normal.dot(direction) > 0.0 {
+            normal = -normal;
+        }
+        closest = Some(MeshHit {
+            distance,
+            point: origin + direction * distance,
+            normal,
+            triangle: index,
+        });
+    }
+
+    closest
+}
+
+/// Intersection la plus proche entre un rayon et un mesh
+///
+/// Les attributs du mesh sont empruntés tels quels ; un mesh sans positions
+/// `Float32x3` n'est jamais touché.
+pub fn ray_mesh_intersection(origin: Vec3, direction: Vec3, mesh: &Mesh) -> Option<MeshHit> {
+    let Some(VertexAttributeValues::Float32x3(positions)) = mesh.attribute(Mesh::ATTRIBUTE_POSITION) else {
+        return None;
+    };
+    match mesh.indices() {
+        Some(indices) => ray_triangles_intersection(origin, direction, positions, indices.iter()),
+        None => ray_triangles_intersection(origin, direction, positions, 0..positions.len()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry;
+
+    #[test]
+    fn ray_hits_triangle_front_face() {
+        let mesh = geometry::create_3d_triangle_mesh(
+            Vec2::new(-10.0, -10.0),
+            Vec2::new(10.0, -10.0),
+            Vec2::new(0.0, 10.0),
+            4.0,
+        );
+
+        let hit = ray_mesh_intersection(Vec3::new(0.0, 0.0, 100.0), Vec3::NEG_Z, &mesh)
+            .expect("le rayon doit toucher le prisme");
+        assert!((hit.distance - 98.0).abs() < 1e-4);
+        assert!((hit.point.z - 2.0).abs() < 1e-4);
+        assert!(hit.normal.abs_diff_eq(Vec3::Z, 1e-4));
+    }
+
+    #[test]
+    fn ray_misses_outside_and_behind() {
+        let mesh = geometry::create_3d_cylinder_mesh(15.0, 10.0, 32);
+
+        assert!(ray_mesh_intersection(Vec3::new(50.0, 0.0, 100.0), Vec3::NEG_Z, &mesh).is_none());
+        assert!(ray_mesh_intersection(Vec3::new(0.0, 0.0, 100.0), Vec3::Z, &mesh).is_none());
+    }
+
+    #[test]
+    fn ring_hole_is_not_hit() {
+        let mesh = geometry::create_3d_ring_mesh(200.0, 170.0, 10.0, 64);
+
+        assert!(ray_mesh_intersection(Vec3::new(0.0, 0.0, 100.0), Vec3::NEG_Z, &mesh).is_none());
+        assert!(ray_mesh_intersection(Vec3::new(185.0, 5.0, 100.0), Vec3::NEG_Z, &mesh).is_some());
+    }
+
+    #[test]
+    fn unindexed_triangles_are_read_in_order() {
+        let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, -5.0], [9.0, 0.0, -5.0], [0.0, 9.0, -5.0]];
+        let hit = ray_triangles_intersection(Vec3::new(2.0, 2.0, 10.0), Vec3::NEG_Z, &positions, 0..positions.len())
+            .expect("le second triangle doit être touché");
+        assert_eq!(hit.triangle, 1);
+        assert!((hit.distance - 15.0).abs() < 1e-4);
+
+        // Indices hors limites : triangle ignoré, le rang des suivants est conservé
+        let hit = ray_triangles_intersection(Vec3::new(2.0, 2.0, 10.0), Vec3::NEG_Z, &positions, [0, 1, 9, 3, 4, 5])
+            .expect("le triangle valide doit être touché");
+        assert_eq!(hit.triangle, 1);
+    }
+}
@@ -0,0 +1,234 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                          FICHIER: src/spec.rs                            ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Spécification du logo
+//!
+//! Regroupe les paramètres d'apparence modifiables à l'exécution, par
+//! opposition aux constantes de `config.rs`.
+
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::file_io::line_error;
+use crate::materials::{self, MaterialPreset, PresetTextures};
+use crate::systems::setup::LogoPart;
+
+/// Ressource décrivant l'apparence du logo
+#[derive(Resource, Clone, Debug)]
+pub struct LogoSpec {
+    /// Paramètres d'animation des matériaux
+    pub material_animation: MaterialAnimationSpec,
+    /// Préréglages de matériaux disponibles
+    pub presets: Vec<MaterialPreset>,
+    /// Préréglage choisi pour chaque catégorie de partie
+    pub part_materials: PartMaterials,
+}
+
+impl Default for LogoSpec {
+    fn default() -> Self {
+        Self {
+            material_animation: MaterialAnimationSpec::default(),
+            presets: materials::material_presets(),
+            part_materials: PartMaterials::default(),
+        }
+    }
+}
+
+impl LogoSpec {
+    /// Préréglage d'une partie (`None` : matériau d'origine de `materials.rs`)
+    ///
+    /// Les textures données par le fichier d'affectation priment sur celles
+    /// du préréglage.
+    pub fn preset_for(&self, part: &LogoPart) -> Option<MaterialPreset> {
+        let choice = self.part_materials.choice_for(part)?;
+        let preset = self.presets.iter().find(|preset| preset.name == choice.preset)?;
+        Some(MaterialPreset { textures: choice.textures.or(&preset.textures), ..preset.clone() })
+    }
+}
+
+/// Préréglage choisi pour une catégorie de partie
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartMaterial {
+    /// Nom du préréglage
+    pub preset: String,
+    /// Textures propres à la catégorie (chemins relatifs à `assets`)
+    pub textures: PresetTextures,
+}
+
+/// Préréglages de matériaux, par catégorie de partie
+///
+/// Format texte (une catégorie par ligne, `#` pour les commentaires) :
+///
+/// ```text
+/// <catégorie> <préréglage> [base_color=<chemin>] [normal=<chemin>] [metallic_roughness=<chemin>] [emissive=<chemin>]
+/// ```
+///
+/// Catégories : `ring`, `exterior`, `interior`, `circle`, `r` ; les espaces
+/// du nom du préréglage s'écrivent `_` (`acier_brossé`). Les chemins de
+/// textures sont relatifs au dossier `assets`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PartMaterials {
+    pub ring: Option<PartMaterial>,
+    pub exterior: Option<PartMaterial>,
+    pub interior: Option<PartMaterial>,
+    pub small_circle: Option<PartMaterial>,
+    pub r: Option<PartMaterial>,
+}
+
+impl PartMaterials {
+    /// Préréglage choisi pour une partie
+    pub fn choice_for(&self, part: &LogoPart) -> Option<&PartMaterial> {
+        match part {
+            LogoPart::Ring => self.ring.as_ref(),
+            LogoPart::ExteriorTriangle(_) => self.exterior.as_ref(),
+            LogoPart::InteriorTriangle(_) => self.interior.as_ref(),
+            LogoPart::SmallCircle(_) => self.small_circle.as_ref(),
+            LogoPart::RPart(_) => self.r.as_ref(),
+        }
+    }
+
+    /// Nom du préréglage choisi pour une partie
+    pub fn name_for(&self, part: &LogoPart) -> Option<&str> {
+        self.choice_for(part).map(|choice| choice.preset.as_str())
+    }
+
+    /// Aucun préréglage : toutes les parties gardent leur matériau d'origine
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Lit une affectation de préréglages ; les noms sont vérifiés dans `presets`
+    pub fn from_text(text: &str, presets: &[MaterialPreset]) -> io::Result<Self> {
+        let mut choices = PartMaterials::default();
+        for (number, line) in text.lines().enumerate() {
+            let words: Vec<&str> = line.split('#').next().unwrap_or("").split_whitespace().collect();
+            let [category, name, ref options @ ..] = words[..] else {
+                if words.is_empty() {
+                    continue;
+                }
+                return Err(line_error(number, "« <catégorie> <préréglage> » attendu"));
+            };
+            let name = name.replace('_', " ");
+            if !presets.iter().any(|preset| preset.name == name) {
+                return Err(line_error(number, format!("préréglage inconnu : {}", name)));
+            }
+            let mut textures = PresetTextures::default();
+            for option in options {
+                let unknown = || line_error(number, format!("option inconnue : {}", option));
+                let (key, path) = option.split_once('=').ok_or_else(unknown)?;
+                let texture = match key {
+                    "base_color" => &mut textures.base_color,
+                    "normal" => &mut textures.normal_map,
+                    "metallic_roughness" => &mut textures.metallic_roughness,
+                    "emissive" => &mut textures.emissive,
+                    _ => return Err(unknown()),
+                };
+                *texture = Some(path.to_string());
+            }
+            let slot = match category {
+                "ring" => &mut choices.ring,
+                "exterior" => &mut choices.exterior,
+                "interior" => &mut choices.interior,
+                "circle" => &mut choices.small_circle,
+                "r" => &mut choices.r,
+                _ => return Err(line_error(number, format!("catégorie inconnue : {}", category))),
+            };
+            *slot = Some(PartMaterial { preset: name, textures });
+        }
+        Ok(choices)
+    }
+
+    /// Charge une affectation de préréglages depuis un fichier
+    pub fn load(path: impl AsRef<Path>, presets: &[MaterialPreset]) -> io::Result<Self> {
+        Self::from_text(&fs::read_to_string(path)?, presets)
+    }
+}
+
+/// Paramètres d'animation des matériaux
+#[derive(Clone, Debug)]
+pub struct MaterialAnimationSpec {
+    /// Écart de teinte entre deux triangles extérieurs voisins (degrés)
+    pub hue_step: f32,
+    /// Vitesse de rotation des teintes autour de l'anneau (degrés par seconde)
+    pub hue_cycle_speed: f32,
+    /// Saturation des triangles arc-en-ciel
+    pub rainbow_saturation: f32,
+    /// Luminosité des triangles arc-en-ciel
+    pub rainbow_lightness: f32,
+    /// Couleur émissive du R au sommet de la pulsation
+    pub r_emissive: LinearRgba,
+    /// Fréquence de pulsation du R (Hz)
+    pub r_pulse_frequency: f32,
+    /// Opacité minimale des petits cercles
+    pub circle_alpha_min: f32,
+    /// Opacité maximale des petits cercles
+    pub circle_alpha_max: f32,
+    /// Période du fondu des petits cercles (secondes)
+    pub circle_fade_period: f32,
+}
+
+impl Default for MaterialAnimationSpec {
+    fn default() -> Self {
+        Self {
+            hue_step: 10.0,
+            hue_cycle_speed: 60.0,
+            rainbow_saturation: 0.8,
+            rainbow_lightness: 0.6,
+            r_emissive: LinearRgba::rgb(1.0, 0.4, 0.0),
+            r_pulse_frequency: 0.8,
+            circle_alpha_min: 0.2,
+            circle_alpha_max: 0.9,
+            circle_fade_period: 3.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_materials_from_text() {
+        let presets = materials::material_presets();
+        let text = "# Métal\nring acier_brossé\n\nr or   # le R doré\n";
+        let choices = PartMaterials::from_text(text, &presets).unwrap();
+        assert_eq!(choices.name_for(&LogoPart::Ring), Some("acier brossé"));
+        assert_eq!(choices.name_for(&LogoPart::RPart("Haut du R")), Some("or"));
+        assert_eq!(choices.name_for(&LogoPart::SmallCircle(0)), None);
+        assert!(!choices.is_empty());
+        assert!(PartMaterials::from_text("# rien\n", &presets).unwrap().is_empty());
+
+        let error = |text: &str| PartMaterials::from_text(text, &presets).unwrap_err().to_string();
+        assert!(error("ring").starts_with("ligne 1"));
+        assert!(error("ring or\nring or argent").starts_with("ligne 2"));
+        assert!(error("ring bois").contains("préréglage inconnu"));
+        assert!(error("socle or").contains("catégorie inconnue"));
+        assert!(error("ring or relief=a.png").contains("option inconnue"));
+        assert!(error("ring or normal").contains("option inconnue"));
+
+        // Textures propres à une catégorie, prioritaires sur celles du préréglage
+        let choices = PartMaterials::from_text("ring acier_brossé normal=textures/n.png emissive=textures/e.png", &presets).unwrap();
+        let spec = LogoSpec { part_materials: choices, ..default() };
+        let preset = spec.preset_for(&LogoPart::Ring).unwrap();
+        assert_eq!(preset.name, "acier brossé");
+        assert_eq!(preset.textures.normal_map.as_deref(), Some("textures/n.png"));
+        assert_eq!(preset.textures.emissive.as_deref(), Some("textures/e.png"));
+        assert!(preset.textures.base_color.is_none());
+        assert!(spec.preset_for(&LogoPart::RPart("Haut du R")).is_none());
+
+        // Le fichier livré est valide
+        let shipped = PartMaterials::from_text(include_str!("../assets/materials.txt"), &presets).unwrap();
+        assert!(!shipped.is_empty());
+        // ... et ses textures existent dans `assets`
+        let choices = [&shipped.ring, &shipped.exterior, &shipped.interior, &shipped.small_circle, &shipped.r];
+        for textures in choices.into_iter().flatten().map(|choice| &choice.textures) {
+            let paths = [&textures.base_color, &textures.normal_map, &textures.metallic_roughness, &textures.emissive];
+            for path in paths.into_iter().flatten() {
+                assert!(Path::new("assets").join(path).is_file(), "{path}");
+            }
+        }
+        assert!(shipped.ring.as_ref().is_some_and(|ring| ring.textures.normal_map.is_some()));
+    }
+}
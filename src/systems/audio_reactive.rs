@@ -0,0 +1,150 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/audio_reactive.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'animation audio-réactive
+//!
+//! Le fichier `assets/<config::AUDIO_FILE>` est analysé hors ligne
+//! (`crate::audio`) puis joué ; l'analyse pilote le logo :
+//! - Amplitude : vitesse de rotation
+//! - Bandes de fréquences : taille des triangles autour de l'anneau
+//! - Graves : pulsation émissive du R
+//!
+//! - Touche O : Lancer / arrêter la musique
+
+use bevy::prelude::*;
+use std::path::Path;
+use crate::{audio, config};
+use crate::audio::AudioAnalysis;
+use crate::spec::LogoSpec;
+use crate::systems::camera::RotatingObject;
+use crate::systems::picking::{set_part_emissive, HoverHighlight};
+use crate::systems::part_motion::{apply_about_pivot, PartPivot};
+use crate::systems::setup::LogoPart;
+
+/// Agrandissement maximal d'un triangle extérieur sur une bande à plein niveau
+const TRIANGLE_GAIN: f32 = 0.8;
+
+/// Accélération maximale de la rotation à pleine amplitude
+const SPEED_GAIN: f32 = 3.0;
+
+/// Ressource contenant l'analyse et la position de lecture
+#[derive(Resource, Default)]
+pub struct AudioReactive {
+    pub analysis: AudioAnalysis,
+    /// Temps de lecture en secondes
+    pub time: f32,
+    pub playing: bool,
+}
+
+/// Composant marker de l'entité qui joue la musique
+#[derive(Component)]
+pub struct ReactiveMusic;
+
+/// Système de lancement et d'avancement de la lecture
+#[allow(clippy::too_many_arguments)]
+pub fn audio_playback_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    mut state: ResMut<AudioReactive>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut logos: Query<&mut RotatingObject>,
+    music: Query<Entity, With<ReactiveMusic>>,
+    parts: Query<(&LogoPart, &MeshMaterial3d<StandardMaterial>, Option<&HoverHighlight>)>,
+) {
+    let mut stop = false;
+
+    if keyboard.just_pressed(KeyCode::KeyO) {
+        if state.playing {
+            stop = true;
+        } else {
+            let path = Path::new("assets").join(config::AUDIO_FILE);
+            match audio::load_wav(&path) {
+                Ok(wav) => {
+                    state.analysis = audio::analyze(
+                        &wav,
+                        config::AUDIO_ANALYSIS_FPS,
+                        config::AUDIO_FFT_SIZE,
+                        config::AUDIO_BANDS,
+                    );
+                    state.time = 0.0;
+                    state.playing = true;
+                    commands.spawn((
+                        AudioPlayer::new(asset_server.load(config::AUDIO_FILE)),
+                        PlaybackSettings::DESPAWN,
+                        ReactiveMusic,
+                    ));
+                    println!("🎵 {} analysé : {:.1} s", path.display(), wav.duration());
+                }
+                Err(e) => println!("⚠ Impossible de lire {} : {}", path.display(), e),
+            }
+        }
+    }
+
+    if state.playing {
+        state.time += time.delta_secs();
+        if state.time >= state.analysis.duration() {
+            stop = true;
+        }
+    }
+
+    if stop {
+        state.playing = false;
+        for entity in music.iter() {
+            commands.entity(entity).despawn();
+        }
+        for mut logo in logos.iter_mut() {
+            logo.speed = RotatingObject::default().speed;
+        }
+        // Le R s'éteint ; l'animation des matériaux reprend la main si active
+        for (part, material_handle, highlight) in parts.iter() {
+            if matches!(part, LogoPart::RPart(_)) {
+                set_part_emissive(&mut materials, &material_handle.0, highlight, LinearRgba::BLACK);
+            }
+        }
+    }
+}
+
+/// Système appliquant l'analyse au logo
+#[allow(clippy::type_complexity)]
+pub fn audio_reactive_system(
+    state: Res<AudioReactive>,
+    spec: Res<LogoSpec>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut logos: Query<&mut RotatingObject>,
+    mut parts: Query<(&LogoPart, &PartPivot, &mut Transform, &MeshMaterial3d<StandardMaterial>, Option<&HoverHighlight>)>,
+) {
+    if !state.playing {
+        return;
+    }
+    let Some(frame) = state.analysis.frame_at(state.time) else {
+        return;
+    };
+
+    // === VITESSE DE ROTATION ===
+    for mut logo in logos.iter_mut() {
+        logo.speed = RotatingObject::default().speed * (1.0 + SPEED_GAIN * frame.amplitude);
+    }
+
+    // Graves : moyenne des deux premières bandes
+    let bass = frame.bands.iter().take(2).sum::<f32>() / 2.0;
+
+    for (part, pivot, mut transform, material_handle, highlight) in parts.iter_mut() {
+        match part {
+            // === SPECTRE AUTOUR DE L'ANNEAU ===
+            LogoPart::ExteriorTriangle(i) => {
+                let band = i * frame.bands.len() / config::EXTERIOR_TRIANGLES_COUNT;
+                let level = frame.bands.get(band).copied().unwrap_or(0.0);
+                let scale = Vec3::splat(1.0 + TRIANGLE_GAIN * level);
+                apply_about_pivot(&mut transform, pivot.0, Quat::IDENTITY, scale);
+            }
+            // === PULSATION DU R ===
+            LogoPart::RPart(_) => {
+                set_part_emissive(&mut materials, &material_handle.0, highlight, spec.material_animation.r_emissive * bass);
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,220 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/bake.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de fusion du logo en un seul mesh
+//!
+//! Les parties sont cuites dans un mesh unique à couleurs par sommet, dessiné
+//! en un seul appel. Les plages d'indices de chaque partie sont conservées :
+//! la sélection et la surbrillance fonctionnent toujours.
+//! - Touche Z : Fusionner / dissocier le logo
+//!
+//! Les parties transparentes (petits cercles) sont cuites dans un second
+//! mesh, dessiné en mode mélange ; le reste forme le mesh opaque.
+//!
+//! Le mesh fusionné est statique : les effets animant les parties ne
+//! s'appliquent qu'au logo dissocié.
+
+use bevy::prelude::*;
+use std::ops::Range;
+use crate::geometry;
+use crate::systems::camera::RotatingObject;
+use crate::systems::intro::IntroState;
+use crate::systems::part_motion::RestTransform;
+use crate::systems::picking::HoveredPart;
+use crate::systems::setup::LogoPart;
+
+/// Éclaircissement de la partie survolée (mélange vers le blanc)
+const HIGHLIGHT_MIX: f32 = 0.4;
+
+/// Partie cuite dans un mesh fusionné
+#[derive(Clone, Debug)]
+pub struct BakedPart {
+    /// Entité d'origine (cachée tant que le logo est fusionné)
+    pub entity: Entity,
+    pub part: LogoPart,
+    pub vertices: Range<u32>,
+    pub indices: Range<u32>,
+}
+
+/// Mesh fusionné d'un logo
+#[derive(Component, Clone, Debug)]
+pub struct BakedLogo {
+    pub parts: Vec<BakedPart>,
+    /// Couleurs par sommet d'origine (avant surbrillance)
+    pub colors: Vec<[f32; 4]>,
+    /// Partie actuellement surlignée (indice dans `parts`)
+    pub highlighted: Option<usize>,
+}
+
+impl BakedLogo {
+    /// Partie contenant le triangle `triangle` du mesh fusionné
+    pub fn part_for_triangle(&self, triangle: usize) -> Option<&BakedPart> {
+        let index = (triangle * 3) as u32;
+        self.parts.iter().find(|part| part.indices.contains(&index))
+    }
+}
+
+/// Système de fusion / dissociation
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn bake_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    intro: Res<IntroState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    logos: Query<(Entity, &Children), With<RotatingObject>>,
+    baked: Query<(Entity, &BakedLogo)>,
+    mut parts: Query<(&LogoPart, &Mesh3d, &MeshMaterial3d<StandardMaterial>, &Transform, Option<&RestTransform>, &mut Visibility)>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyZ) {
+        return;
+    }
+    if intro.playing {
+        println!("⚠ Fusion impossible pendant l'apparition");
+        return;
+    }
+
+    // === DISSOCIATION ===
+    if !baked.is_empty() {
+        for (entity, logo) in baked.iter() {
+            for part in &logo.parts {
+                if let Ok((.., mut visibility)) = parts.get_mut(part.entity) {
+                    *visibility = Visibility::Inherited;
+                }
+            }
+            commands.entity(entity).despawn();
+        }
+        println!("🧱 Logo dissocié");
+        return;
+    }
+
+    // === FUSION DE CHAQUE LOGO ===
+    let opaque = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        metallic: 0.3,
+        perceptual_roughness: 0.5,
+        ..default()
+    });
+    let blended = materials.add(StandardMaterial {
+        base_color: Color::WHITE,
+        metallic: 0.3,
+        perceptual_roughness: 0.5,
+        alpha_mode: AlphaMode::Blend,
+        ..default()
+    });
+    for (logo, children) in logos.iter() {
+        let mut sources = Vec::new();
+        for child in children.iter() {
+            let Ok((part, mesh_handle, material_handle, transform, rest, _)) = parts.get(child) else {
+                continue;
+            };
+            let (Some(mesh), Some(part_material)) = (meshes.get(&mesh_handle.0), materials.get(&material_handle.0)) else {
+                continue;
+            };
+            let transform = rest.map_or(*transform, |rest| rest.0);
+            let translucent = !matches!(part_material.alpha_mode, AlphaMode::Opaque | AlphaMode::Mask(_));
+            sources.push((child, *part, mesh.clone(), transform, part_material.base_color.to_linear(), translucent));
+        }
+
+        for (translucent, material) in [(false, &opaque), (true, &blended)] {
+            let group: Vec<_> = sources.iter().filter(|source| source.5 == translucent).collect();
+            if group.is_empty() {
+                continue;
+            }
+
+            let inputs: Vec<_> = group.iter().map(|(_, _, mesh, transform, color, _)| (mesh, *transform, *color)).collect();
+            let (mesh, ranges) = geometry::merge_meshes(&inputs);
+            let colors = match mesh.attribute(Mesh::ATTRIBUTE_COLOR) {
+                Some(bevy::render::mesh::VertexAttributeValues::Float32x4(colors)) => colors.clone(),
+                _ => Vec::new(),
+            };
+            let baked_parts: Vec<BakedPart> = group
+                .iter()
+                .zip(ranges)
+                .map(|((entity, part, ..), range)| BakedPart {
+                    entity: *entity,
+                    part: *part,
+                    vertices: range.vertices,
+                    indices: range.indices,
+                })
+                .collect();
+
+            for part in &baked_parts {
+                if let Ok((.., mut visibility)) = parts.get_mut(part.entity) {
+                    *visibility = Visibility::Hidden;
+                }
+            }
+            println!(
+                "🧱 Logo fusionné ({}) : {} parties, {} triangles",
+                if translucent { "transparent" } else { "opaque" },
+                baked_parts.len(),
+                mesh.indices().map_or(0, |i| i.len() / 3),
+            );
+
+            let baked_entity = commands
+                .spawn((
+                    Mesh3d(meshes.add(mesh)),
+                    MeshMaterial3d(material.clone()),
+                    Transform::IDENTITY,
+                    BakedLogo { parts: baked_parts, colors, highlighted: None },
+                ))
+                .id();
+            commands.entity(logo).add_child(baked_entity);
+        }
+    }
+}
+
+/// Système de surbrillance des parties d'un mesh fusionné
+///
+/// Les couleurs par sommet de la partie survolée sont éclaircies ; les
+/// autres reprennent leur couleur d'origine.
+pub fn baked_highlight_system(
+    hovered: Res<HoveredPart>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(&mut BakedLogo, &Mesh3d)>,
+) {
+    let target = hovered.0.map(|hit| hit.entity);
+    for (mut logo, mesh_handle) in query.iter_mut() {
+        let highlighted = target.and_then(|entity| logo.parts.iter().position(|part| part.entity == entity));
+        if highlighted == logo.highlighted {
+            continue;
+        }
+        logo.highlighted = highlighted;
+
+        let mut colors = logo.colors.clone();
+        if let Some(index) = highlighted {
+            let range = logo.parts[index].vertices.clone();
+            for color in &mut colors[range.start as usize..range.end as usize] {
+                for channel in &mut color[..3] {
+                    *channel += (1.0 - *channel) * HIGHLIGHT_MIX;
+                }
+            }
+        }
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangles_map_back_to_their_part() {
+        let part = |index: u32, indices: Range<u32>| BakedPart {
+            entity: Entity::from_raw(index),
+            part: LogoPart::ExteriorTriangle(index as usize),
+            vertices: 0..0,
+            indices,
+        };
+        let logo = BakedLogo { parts: vec![part(0, 0..6), part(1, 6..36)], colors: Vec::new(), highlighted: None };
+
+        assert_eq!(logo.part_for_triangle(0).unwrap().entity, Entity::from_raw(0));
+        assert_eq!(logo.part_for_triangle(1).unwrap().entity, Entity::from_raw(0));
+        assert_eq!(logo.part_for_triangle(2).unwrap().entity, Entity::from_raw(1));
+        assert_eq!(logo.part_for_triangle(11).unwrap().entity, Entity::from_raw(1));
+        assert!(logo.part_for_triangle(12).is_none());
+    }
+}
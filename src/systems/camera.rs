@@ -8,10 +8,25 @@
 //! - Rotation avec bouton gauche de la souris
 //! - Zoom avec molette
 //! - Réinitialisation avec touche R
+//! - Bascule perspective / orthographique avec touche P
 
 use bevy::prelude::*;
 use bevy::input::mouse::{MouseMotion, MouseWheel};
+use bevy::render::camera::ScalingMode;
+use bevy::window::PrimaryWindow;
 use crate::config;
+use crate::systems::fly_camera::FlyCamera;
+use crate::systems::viewports::{camera_has_input, ViewportLayout};
+
+/// Mode de projection de la caméra orbitale
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ProjectionMode {
+    /// Projection en perspective (vue normale)
+    #[default]
+    Perspective,
+    /// Projection orthographique (dessin technique)
+    Orthographic,
+}
 
 /// Composant marker pour identifier la caméra contrôlable
 #[derive(Component)]
@@ -28,6 +43,30 @@ pub struct OrbitCamera {
     pub min_distance: f32,
     /// Distance maximale de zoom
     pub max_distance: f32,
+    /// Mode de projection actuel
+    pub projection_mode: ProjectionMode,
+    /// Hauteur visible en mode orthographique (unités monde)
+    pub ortho_scale: f32,
+    /// Champ de vision vertical en mode perspective (radians)
+    pub fov: f32,
+    /// Plan de coupe proche
+    pub near: f32,
+    /// Plan de coupe lointain
+    pub far: f32,
+    /// Distance restaurée par la touche R
+    pub home_distance: f32,
+    /// Point focal restauré par la touche R
+    pub home_focus: Vec3,
+    /// Angle horizontal restauré par la touche R
+    pub home_yaw: f32,
+    /// Angle vertical restauré par la touche R
+    pub home_pitch: f32,
+    /// Axe vertical de l'orbite
+    pub up: Vec3,
+    /// Angle vertical minimal (peut dépasser -π/2 pour passer sous le pôle)
+    pub min_pitch: f32,
+    /// Angle vertical maximal (peut dépasser π/2 pour passer au-dessus du pôle)
+    pub max_pitch: f32,
 }
 
 impl Default for OrbitCamera {
@@ -39,78 +78,216 @@ impl Default for OrbitCamera {
             focus: Vec3::ZERO,
             min_distance: 200.0,
             max_distance: 1500.0,
+            projection_mode: ProjectionMode::Perspective,
+            ortho_scale: perspective_visible_height(config::CAMERA_DISTANCE, PerspectiveProjection::default().fov),
+            fov: PerspectiveProjection::default().fov,
+            near: PerspectiveProjection::default().near,
+            far: PerspectiveProjection::default().far,
+            home_distance: config::CAMERA_DISTANCE,
+            home_focus: Vec3::ZERO,
+            home_yaw: 0.0,
+            home_pitch: config::CAMERA_ANGLE.to_radians(),
+            up: Vec3::Y,
+            min_pitch: config::CAMERA_MIN_PITCH,
+            max_pitch: config::CAMERA_MAX_PITCH,
         }
     }
 }
 
+/// Rotation amenant l'axe Y sur l'axe vertical choisi
+pub fn up_basis(up: Vec3) -> Quat {
+    Quat::from_rotation_arc(Vec3::Y, up.normalize())
+}
+
+/// Convertit des coordonnées sphériques en position relative au point focal
+///
+/// Le lacet tourne autour de `up`, le tangage élève la caméra vers `up`.
+pub fn spherical_to_cartesian(yaw: f32, pitch: f32, distance: f32, up: Vec3) -> Vec3 {
+    let local = Vec3::new(
+        distance * pitch.cos() * yaw.sin(),
+        distance * pitch.sin(),
+        distance * pitch.cos() * yaw.cos(),
+    );
+    up_basis(up) * local
+}
+
+/// Convertit une position relative au point focal en (lacet, tangage, distance)
+///
+/// Le tangage retourné est compris entre -π/2 et π/2.
+pub fn cartesian_to_spherical(offset: Vec3, up: Vec3) -> (f32, f32, f32) {
+    let local = up_basis(up).inverse() * offset;
+    let distance = local.length();
+    if distance <= f32::EPSILON {
+        return (0.0, 0.0, 0.0);
+    }
+    let yaw = local.x.atan2(local.z);
+    let pitch = (local.y / distance).clamp(-1.0, 1.0).asin();
+    (yaw, pitch, distance)
+}
+
+/// Orientation de la caméra orbitale, calculée sans `looking_at`
+///
+/// L'orientation varie continûment avec le tangage, y compris au passage
+/// des pôles, au lieu de se retourner brusquement.
+pub fn orbit_rotation(yaw: f32, pitch: f32, up: Vec3) -> Quat {
+    up_basis(up) * Quat::from_rotation_y(yaw) * Quat::from_rotation_x(-pitch)
+}
+
+/// Transform de la caméra correspondant à l'état de l'orbite
+pub fn orbit_transform(orbit: &OrbitCamera) -> Transform {
+    Transform {
+        translation: orbit.focus
+            + spherical_to_cartesian(orbit.yaw, orbit.pitch, orbit.distance, orbit.up),
+        rotation: orbit_rotation(orbit.yaw, orbit.pitch, orbit.up),
+        ..default()
+    }
+}
+
+/// Hauteur visible au point focal pour une caméra perspective
+pub fn perspective_visible_height(distance: f32, fov: f32) -> f32 {
+    2.0 * distance * (fov / 2.0).tan()
+}
+
+/// Distance perspective donnant la même hauteur visible qu'une vue orthographique
+pub fn distance_for_visible_height(height: f32, fov: f32) -> f32 {
+    height / (2.0 * (fov / 2.0).tan())
+}
+
+/// Construit la projection Bevy correspondant à l'état de la caméra orbitale
+pub fn build_projection(orbit: &OrbitCamera) -> Projection {
+    match orbit.projection_mode {
+        ProjectionMode::Perspective => Projection::Perspective(PerspectiveProjection {
+            fov: orbit.fov,
+            near: orbit.near,
+            far: orbit.far,
+            ..default()
+        }),
+        ProjectionMode::Orthographic => Projection::Orthographic(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical { viewport_height: 1.0 },
+            scale: orbit.ortho_scale,
+            far: orbit.far,
+            ..OrthographicProjection::default_3d()
+        }),
+    }
+}
+
 /// Système de contrôle de la caméra avec la souris
 /// 
 /// Contrôles :
 /// - Clic gauche + déplacement souris : Rotation (orbite)
 /// - Molette : Zoom in/out
 /// - Touche R : Réinitialiser la vue
+/// - Touche P : Basculer perspective / orthographique
 pub fn camera_control_system(
     mouse_button: Res<ButtonInput<MouseButton>>,
     keyboard: Res<ButtonInput<KeyCode>>,
     mut mouse_motion: EventReader<MouseMotion>,
     mut mouse_wheel: EventReader<MouseWheel>,
-    mut query: Query<(&mut OrbitCamera, &mut Transform)>,
+    layout: Res<ViewportLayout>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut query: Query<(&mut OrbitCamera, &mut Transform, &mut Projection, &Camera, Option<&FlyCamera>)>,
 ) {
-    for (mut orbit, mut transform) in query.iter_mut() {
+    // Les événements sont lus une seule fois puis distribués aux caméras
+    // concernées (celle sous le curseur, ou toutes si les vues sont liées)
+    let motions: Vec<Vec2> = if mouse_button.pressed(MouseButton::Left) {
+        mouse_motion.read().map(|motion| motion.delta).collect()
+    } else {
+        // Vider les événements non utilisés
+        mouse_motion.clear();
+        Vec::new()
+    };
+    let wheels: Vec<f32> = mouse_wheel.read().map(|wheel| wheel.y).collect();
+    let cursor = windows.single().ok().and_then(|window| window.cursor_position());
+
+    for (mut orbit, mut transform, mut projection, camera, fly) in query.iter_mut() {
+        // La caméra libre a la main : l'orbite est suspendue
+        if fly.is_some_and(|fly| fly.active) {
+            continue;
+        }
+        
+        let has_input = camera_has_input(&layout, camera, cursor);
         
         // === ROTATION AVEC SOURIS ===
         // Clic gauche maintenu + déplacement de la souris
-        if mouse_button.pressed(MouseButton::Left) {
-            for motion in mouse_motion.read() {
-                // Sensibilité de la rotation
-                let sensitivity = 0.003;
-                
-                // Mise à jour des angles
-                orbit.yaw -= motion.delta.x * sensitivity;
-                orbit.pitch -= motion.delta.y * sensitivity;
-                
-                // Limitation de l'angle vertical (éviter le gimbal lock)
-                // On empêche de passer complètement au-dessus ou en-dessous
-                orbit.pitch = orbit.pitch.clamp(-1.5, 1.5);
-            }
-        } else {
-            // Vider les événements non utilisés
-            mouse_motion.clear();
+        for delta in motions.iter().filter(|_| has_input) {
+            // Sensibilité de la rotation
+            let sensitivity = 0.003;
+            
+            // Caméra à l'envers après un pôle : le lacet s'inverse à l'écran
+            let yaw_sign = if orbit.pitch.cos() < 0.0 { -1.0 } else { 1.0 };
+            
+            // Mise à jour des angles
+            orbit.yaw -= delta.x * sensitivity * yaw_sign;
+            orbit.pitch -= delta.y * sensitivity;
+            
+            // Limitation de l'angle vertical selon les bornes configurées
+            orbit.pitch = orbit.pitch.clamp(orbit.min_pitch, orbit.max_pitch);
         }
         
         // === ZOOM AVEC MOLETTE ===
-        for wheel in mouse_wheel.read() {
+        for wheel in wheels.iter().filter(|_| has_input) {
             // Sensibilité du zoom
             let zoom_speed = 20.0;
             
-            // Mise à jour de la distance
-            orbit.distance -= wheel.y * zoom_speed;
-            
-            // Limitation du zoom
-            orbit.distance = orbit.distance.clamp(
-                orbit.min_distance,
-                orbit.max_distance
-            );
+            match orbit.projection_mode {
+                ProjectionMode::Perspective => {
+                    // Mise à jour de la distance
+                    orbit.distance -= wheel * zoom_speed;
+                    
+                    // Limitation du zoom
+                    orbit.distance = orbit.distance.clamp(
+                        orbit.min_distance,
+                        orbit.max_distance
+                    );
+                }
+                ProjectionMode::Orthographic => {
+                    // La molette agit sur l'échelle, exprimée comme une distance équivalente
+                    // pour garder la même sensibilité et les mêmes limites qu'en perspective
+                    let equivalent = distance_for_visible_height(orbit.ortho_scale, orbit.fov)
+                        - wheel * zoom_speed;
+                    let equivalent = equivalent.clamp(orbit.min_distance, orbit.max_distance);
+                    orbit.ortho_scale = perspective_visible_height(equivalent, orbit.fov);
+                }
+            }
         }
         
-        // === RÉINITIALISATION AVEC TOUCHE R ===
-        if keyboard.just_pressed(KeyCode::KeyR) {
-            orbit.distance = config::CAMERA_DISTANCE;
-            orbit.yaw = 0.0;
-            orbit.pitch = config::CAMERA_ANGLE.to_radians();
+        // === BASCULE PERSPECTIVE / ORTHOGRAPHIQUE AVEC TOUCHE P ===
+        // La taille apparente du logo au point focal est conservée
+        if has_input && keyboard.just_pressed(KeyCode::KeyP) {
+            match orbit.projection_mode {
+                ProjectionMode::Perspective => {
+                    orbit.ortho_scale = perspective_visible_height(orbit.distance, orbit.fov);
+                    orbit.projection_mode = ProjectionMode::Orthographic;
+                }
+                ProjectionMode::Orthographic => {
+                    orbit.distance = distance_for_visible_height(orbit.ortho_scale, orbit.fov)
+                        .clamp(orbit.min_distance, orbit.max_distance);
+                    orbit.projection_mode = ProjectionMode::Perspective;
+                }
+            }
+            *projection = build_projection(&orbit);
         }
         
-        // === CALCUL DE LA NOUVELLE POSITION ===
-        // Conversion des coordonnées sphériques en cartésiennes
-        let x = orbit.distance * orbit.pitch.cos() * orbit.yaw.sin();
-        let y = orbit.distance * orbit.pitch.sin();
-        let z = orbit.distance * orbit.pitch.cos() * orbit.yaw.cos();
+        // === RÉINITIALISATION AVEC TOUCHE R ===
+        if has_input && keyboard.just_pressed(KeyCode::KeyR) {
+            orbit.distance = orbit.home_distance;
+            orbit.focus = orbit.home_focus;
+            orbit.yaw = orbit.home_yaw;
+            orbit.pitch = orbit.home_pitch;
+            orbit.ortho_scale = perspective_visible_height(orbit.home_distance, orbit.fov);
+        }
         
-        // Mise à jour de la position de la caméra
-        transform.translation = orbit.focus + Vec3::new(x, y, z);
+        // === MISE À JOUR DE L'ÉCHELLE ORTHOGRAPHIQUE ===
+        if let Projection::Orthographic(ortho) = projection.as_mut()
+            && ortho.scale != orbit.ortho_scale
+        {
+            ortho.scale = orbit.ortho_scale;
+        }
         
-        // La caméra regarde toujours vers le point focal
-        *transform = transform.looking_at(orbit.focus, Vec3::Y);
+        // === CALCUL DE LA NOUVELLE POSITION ===
+        // Conversion des coordonnées sphériques en cartésiennes,
+        // la caméra regardant toujours vers le point focal
+        *transform = orbit_transform(&orbit);
     }
 }
 
@@ -133,10 +310,59 @@ impl Default for RotatingObject {
 /// Système qui fait tourner automatiquement les objets marqués
 pub fn rotate_object_system(
     time: Res<Time>,
-    mut query: Query<&mut Transform, With<RotatingObject>>,
+    mut query: Query<(&mut Transform, &RotatingObject)>,
 ) {
-    for mut transform in query.iter_mut() {
+    for (mut transform, object) in query.iter_mut() {
         // Rotation autour de l'axe Y (vertical)
-        transform.rotate_y(time.delta_secs() * 0.5);
+        transform.rotate_y(time.delta_secs() * object.speed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f32::consts::FRAC_PI_2;
+
+    #[test]
+    fn spherical_round_trip() {
+        for up in [Vec3::Y, Vec3::Z, Vec3::new(1.0, 1.0, 0.0).normalize()] {
+            let offset = spherical_to_cartesian(0.7, 0.4, 500.0, up);
+            let (yaw, pitch, distance) = cartesian_to_spherical(offset, up);
+            assert!((yaw - 0.7).abs() < 1e-4);
+            assert!((pitch - 0.4).abs() < 1e-4);
+            assert!((distance - 500.0).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn positive_pitch_is_above_focus() {
+        let offset = spherical_to_cartesian(0.0, 0.5, 100.0, Vec3::Y);
+        assert!(offset.y > 0.0);
+        let offset = spherical_to_cartesian(0.0, 0.5, 100.0, Vec3::Z);
+        assert!(offset.z > 0.0);
+    }
+
+    #[test]
+    fn rotation_looks_at_focus() {
+        let orbit = OrbitCamera { yaw: 1.2, pitch: -0.3, focus: Vec3::new(5.0, 1.0, -2.0), ..default() };
+        let transform = orbit_transform(&orbit);
+        let to_focus = (orbit.focus - transform.translation).normalize();
+        assert!(transform.forward().dot(to_focus) > 0.9999);
+    }
+
+    #[test]
+    fn spawn_transform_matches_control_system() {
+        // La caméra est créée au même endroit que celui recalculé à chaque image
+        let orbit = OrbitCamera::default();
+        let transform = orbit_transform(&orbit);
+        assert!(transform.translation.y > 0.0);
+        assert!((transform.translation.length() - orbit.distance).abs() < 1e-2);
+    }
+
+    #[test]
+    fn passing_the_pole_does_not_flip() {
+        let before = orbit_rotation(0.3, FRAC_PI_2 - 0.01, Vec3::Y);
+        let after = orbit_rotation(0.3, FRAC_PI_2 + 0.01, Vec3::Y);
+        assert!(before.angle_between(after) < 0.03);
     }
 }
@@ -0,0 +1,400 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/camera_path.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de chemins de caméra (keyframes)
+//!
+//! Ce module permet d'enregistrer des états de la caméra orbitale, de les
+//! interpoler par splines de Catmull-Rom et de les rejouer :
+//! - Touche K : Ajouter une keyframe
+//! - Touche J : Enregistrement continu (démarrer / arrêter)
+//! - Touche L : Lecture / arrêt (tour complet si le chemin est vide)
+//! - Retour arrière : Effacer le chemin
+//! - F5 / F9 : Sauvegarder / charger le chemin
+//! - F12 : Export image par image (pas de temps fixe, une capture par image)
+
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot};
+use std::f32::consts::TAU;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::config;
+use crate::file_io::line_error;
+use crate::systems::camera::OrbitCamera;
+use crate::systems::viewports::ViewportSlot;
+
+/// Un état enregistré de la caméra orbitale
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CameraKeyframe {
+    /// Instant de la keyframe en secondes
+    pub time: f32,
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub focus: Vec3,
+}
+
+impl CameraKeyframe {
+    /// Capture l'état actuel d'une caméra orbitale
+    pub fn from_orbit(time: f32, orbit: &OrbitCamera) -> Self {
+        Self {
+            time,
+            yaw: orbit.yaw,
+            pitch: orbit.pitch,
+            distance: orbit.distance,
+            focus: orbit.focus,
+        }
+    }
+
+    /// Applique la keyframe à une caméra orbitale
+    pub fn apply(&self, orbit: &mut OrbitCamera) {
+        orbit.yaw = self.yaw;
+        orbit.pitch = self.pitch;
+        orbit.distance = self.distance;
+        orbit.focus = self.focus;
+    }
+}
+
+/// Chemin de caméra : keyframes triées par temps croissant
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CameraPath {
+    pub keyframes: Vec<CameraKeyframe>,
+}
+
+impl CameraPath {
+    /// Crée un tour complet autour du point focal (export « turntable »)
+    pub fn turntable(orbit: &OrbitCamera, duration: f32, key_count: usize) -> Self {
+        let key_count = key_count.max(2);
+        let keyframes = (0..=key_count)
+            .map(|i| {
+                let t = i as f32 / key_count as f32;
+                CameraKeyframe {
+                    time: t * duration,
+                    yaw: orbit.yaw + t * TAU,
+                    ..CameraKeyframe::from_orbit(0.0, orbit)
+                }
+            })
+            .collect();
+        Self { keyframes }
+    }
+
+    /// Durée totale du chemin
+    pub fn duration(&self) -> f32 {
+        self.keyframes.last().map(|k| k.time).unwrap_or(0.0)
+    }
+
+    /// Ajoute une keyframe à la fin du chemin
+    ///
+    /// Le lacet est « déroulé » par rapport à la keyframe précédente pour que
+    /// l'interpolation prenne toujours le chemin le plus court. Une keyframe
+    /// qui n'avance pas dans le temps est ignorée : les temps restent
+    /// strictement croissants, comme l'exige `from_text`.
+    pub fn push(&mut self, mut keyframe: CameraKeyframe) {
+        if let Some(last) = self.keyframes.last() {
+            if keyframe.time <= last.time {
+                return;
+            }
+            let delta = (keyframe.yaw - last.yaw + TAU / 2.0).rem_euclid(TAU) - TAU / 2.0;
+            keyframe.yaw = last.yaw + delta;
+        }
+        self.keyframes.push(keyframe);
+    }
+
+    /// Échantillonne le chemin à l'instant `time` (spline de Catmull-Rom)
+    ///
+    /// Le résultat ne dépend que de `time`, ce qui rend la lecture déterministe.
+    pub fn sample(&self, time: f32) -> Option<CameraKeyframe> {
+        let keys = &self.keyframes;
+        let first = keys.first()?;
+        let last = keys.last()?;
+        if keys.len() == 1 || time <= first.time {
+            return Some(CameraKeyframe { time, ..*first });
+        }
+        if time >= last.time {
+            return Some(CameraKeyframe { time, ..*last });
+        }
+
+        // Segment [i, i + 1] contenant l'instant demandé
+        let i = keys.windows(2).position(|w| time < w[1].time).unwrap_or(keys.len() - 2);
+        let p0 = keys[i.saturating_sub(1)];
+        let p1 = keys[i];
+        let p2 = keys[i + 1];
+        let p3 = keys[(i + 2).min(keys.len() - 1)];
+
+        let span = p2.time - p1.time;
+        let u = if span > 0.0 { (time - p1.time) / span } else { 0.0 };
+
+        Some(CameraKeyframe {
+            time,
+            yaw: catmull_rom(p0.yaw, p1.yaw, p2.yaw, p3.yaw, u),
+            pitch: catmull_rom(p0.pitch, p1.pitch, p2.pitch, p3.pitch, u),
+            distance: catmull_rom(p0.distance, p1.distance, p2.distance, p3.distance, u),
+            focus: Vec3::new(
+                catmull_rom(p0.focus.x, p1.focus.x, p2.focus.x, p3.focus.x, u),
+                catmull_rom(p0.focus.y, p1.focus.y, p2.focus.y, p3.focus.y, u),
+                catmull_rom(p0.focus.z, p1.focus.z, p2.focus.z, p3.focus.z, u),
+            ),
+        })
+    }
+
+    /// Sérialise le chemin au format texte (une keyframe par ligne)
+    pub fn to_text(&self) -> String {
+        let mut text = String::from("# time yaw pitch distance focus_x focus_y focus_z\n");
+        for k in &self.keyframes {
+            text.push_str(&format!(
+                "{} {} {} {} {} {} {}\n",
+                k.time, k.yaw, k.pitch, k.distance, k.focus.x, k.focus.y, k.focus.z
+            ));
+        }
+        text
+    }
+
+    /// Lit un chemin au format texte (temps strictement croissants)
+    pub fn from_text(text: &str) -> io::Result<Self> {
+        let mut path = CameraPath::default();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let values: Vec<f32> = line
+                .split_whitespace()
+                .map(|v| v.parse::<f32>())
+                .collect::<Result<_, _>>()
+                .map_err(|e| line_error(number, e))?;
+            if values.len() != 7 {
+                return Err(line_error(number, format!("7 valeurs attendues, {} trouvées", values.len())));
+            }
+            if path.keyframes.last().is_some_and(|k| values[0] <= k.time) {
+                return Err(line_error(number, "temps non croissant"));
+            }
+            path.keyframes.push(CameraKeyframe {
+                time: values[0],
+                yaw: values[1],
+                pitch: values[2],
+                distance: values[3],
+                focus: Vec3::new(values[4], values[5], values[6]),
+            });
+        }
+        Ok(path)
+    }
+
+    /// Sauvegarde le chemin dans un fichier
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_text())
+    }
+
+    /// Charge un chemin depuis un fichier
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_text(&fs::read_to_string(path)?)
+    }
+}
+
+/// Interpolation de Catmull-Rom entre `p1` et `p2` (u dans [0, 1])
+pub fn catmull_rom(p0: f32, p1: f32, p2: f32, p3: f32, u: f32) -> f32 {
+    let u2 = u * u;
+    let u3 = u2 * u;
+    0.5 * ((2.0 * p1)
+        + (-p0 + p2) * u
+        + (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3) * u2
+        + (-p0 + 3.0 * p1 - 3.0 * p2 + p3) * u3)
+}
+
+/// Ressource d'enregistrement et de lecture du chemin de caméra
+#[derive(Resource, Default)]
+pub struct CameraPathPlayer {
+    pub path: CameraPath,
+    /// Lecture en cours
+    pub playing: bool,
+    /// Enregistrement continu en cours
+    pub recording: bool,
+    /// Temps courant de lecture ou d'enregistrement
+    pub time: f32,
+    /// Pas de temps fixe (export image par image) ; temps réel si `None`
+    pub fixed_step: Option<f32>,
+    /// Numéro de la prochaine image exportée
+    pub export_frame: usize,
+    /// Temps écoulé depuis le dernier échantillon enregistré
+    since_last_sample: f32,
+}
+
+/// Système d'enregistrement et de lecture du chemin de caméra
+pub fn camera_path_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut player: ResMut<CameraPathPlayer>,
+    mut query: Query<(&mut OrbitCamera, &ViewportSlot)>,
+) {
+    // Le chemin pilote la vue principale
+    let Some((mut orbit, _)) = query.iter_mut().find(|(_, slot)| slot.0 == 0) else {
+        return;
+    };
+    let dt = player.fixed_step.unwrap_or(time.delta_secs());
+
+    // === AJOUT D'UNE KEYFRAME ===
+    if keyboard.just_pressed(KeyCode::KeyK) {
+        let time = if player.path.keyframes.is_empty() {
+            0.0
+        } else {
+            player.path.duration() + config::CAMERA_PATH_KEY_INTERVAL
+        };
+        player.path.push(CameraKeyframe::from_orbit(time, &orbit));
+        println!("🎥 Keyframe {} ajoutée à {:.1} s", player.path.keyframes.len(), time);
+    }
+
+    // === ENREGISTREMENT CONTINU ===
+    if keyboard.just_pressed(KeyCode::KeyJ) {
+        player.recording = !player.recording;
+        player.playing = false;
+        if player.recording {
+            player.path = CameraPath::default();
+            player.time = 0.0;
+            player.since_last_sample = config::CAMERA_PATH_RECORD_INTERVAL;
+            println!("🔴 Enregistrement du chemin de caméra");
+        } else {
+            println!("⏹ Enregistrement terminé : {} keyframes", player.path.keyframes.len());
+        }
+    }
+    if player.recording {
+        player.time += dt;
+        player.since_last_sample += dt;
+        if player.since_last_sample >= config::CAMERA_PATH_RECORD_INTERVAL {
+            player.since_last_sample = 0.0;
+            let time = player.time;
+            player.path.push(CameraKeyframe::from_orbit(time, &orbit));
+        }
+    }
+
+    // === EFFACEMENT ===
+    if keyboard.just_pressed(KeyCode::Backspace) {
+        player.path = CameraPath::default();
+        player.playing = false;
+        println!("🗑 Chemin de caméra effacé");
+    }
+
+    // === SAUVEGARDE / CHARGEMENT ===
+    if keyboard.just_pressed(KeyCode::F5) {
+        match player.path.save(config::CAMERA_PATH_FILE) {
+            Ok(()) => println!("💾 Chemin sauvegardé dans {}", config::CAMERA_PATH_FILE),
+            Err(e) => println!("⚠ Sauvegarde impossible : {}", e),
+        }
+    }
+    if keyboard.just_pressed(KeyCode::F9) {
+        match CameraPath::load(config::CAMERA_PATH_FILE) {
+            Ok(path) => {
+                println!("📂 Chemin chargé : {} keyframes", path.keyframes.len());
+                player.path = path;
+                player.playing = false;
+            }
+            Err(e) => println!("⚠ Chargement impossible : {}", e),
+        }
+    }
+
+    // === LECTURE ===
+    if keyboard.just_pressed(KeyCode::KeyL) {
+        player.playing = !player.playing;
+        player.recording = false;
+        player.time = 0.0;
+        player.fixed_step = None;
+        if player.playing && player.path.keyframes.is_empty() {
+            player.path = CameraPath::turntable(&orbit, 12.0, 8);
+        }
+    }
+
+    // === EXPORT IMAGE PAR IMAGE ===
+    // Le temps avance d'un pas fixe par image rendue : la séquence ne
+    // dépend pas de la vitesse de la machine
+    if keyboard.just_pressed(KeyCode::F12) {
+        match std::fs::create_dir_all(config::CAMERA_PATH_EXPORT_DIR) {
+            Ok(()) => {
+                if player.path.keyframes.is_empty() {
+                    player.path = CameraPath::turntable(&orbit, 12.0, 8);
+                }
+                player.playing = true;
+                player.recording = false;
+                player.time = 0.0;
+                player.fixed_step = Some(1.0 / config::CAMERA_PATH_EXPORT_FPS);
+                player.export_frame = 0;
+                println!("🎞 Export du chemin dans {}/", config::CAMERA_PATH_EXPORT_DIR);
+            }
+            Err(e) => println!("⚠ Export impossible : {}", e),
+        }
+    }
+
+    if player.playing {
+        if let Some(keyframe) = player.path.sample(player.time) {
+            keyframe.apply(&mut orbit);
+        }
+        if player.fixed_step.is_some() {
+            let file = format!("{}/frame_{:04}.png", config::CAMERA_PATH_EXPORT_DIR, player.export_frame);
+            commands.spawn(Screenshot::primary_window()).observe(save_to_disk(file));
+            player.export_frame += 1;
+        }
+        player.time += dt;
+        if player.time > player.path.duration() {
+            player.playing = false;
+            if player.fixed_step.take().is_some() {
+                println!("🎞 Export terminé : {} images", player.export_frame);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_path() -> CameraPath {
+        let keyframes = [(0.0, 0.0, 0.2, 600.0), (1.5, 1.0, 0.4, 500.0), (2.0, 2.5, -0.1, 650.0), (4.0, 3.0, 0.3, 700.0)]
+            .into_iter()
+            .map(|(time, yaw, pitch, distance)| CameraKeyframe {
+                time,
+                yaw,
+                pitch,
+                distance,
+                focus: Vec3::new(yaw * 10.0, 0.0, -pitch),
+            })
+            .collect();
+        CameraPath { keyframes }
+    }
+
+    #[test]
+    fn sample_passes_through_keyframes() {
+        let path = sample_path();
+        for key in &path.keyframes {
+            let sample = path.sample(key.time).unwrap();
+            assert!((sample.yaw - key.yaw).abs() < 1e-4);
+            assert!((sample.pitch - key.pitch).abs() < 1e-4);
+            assert!((sample.distance - key.distance).abs() < 1e-2);
+            assert!(sample.focus.distance(key.focus) < 1e-3);
+        }
+        assert!(CameraPath::default().sample(1.0).is_none());
+    }
+
+    #[test]
+    fn text_round_trip() {
+        let path = sample_path();
+        assert_eq!(CameraPath::from_text(&path.to_text()).unwrap(), path);
+
+        // Temps qui reculent ou stagnent, nombre de valeurs incorrect
+        assert!(CameraPath::from_text("1 0 0 500 0 0 0\n0.5 0 0 500 0 0 0").is_err());
+        assert!(CameraPath::from_text("1 0 0 500 0 0 0\n1 0 0 500 0 0 0").is_err());
+        assert!(CameraPath::from_text("1 0 0 500").is_err());
+    }
+
+    #[test]
+    fn recorded_path_exports_and_reloads() {
+        // Deux enregistrements dans la même image : le second est ignoré
+        let mut path = CameraPath::default();
+        for key in sample_path().keyframes {
+            path.push(key);
+            path.push(CameraKeyframe { yaw: key.yaw + 1.0, ..key });
+        }
+        path.push(CameraKeyframe { time: 3.0, ..path.keyframes[0] });
+        let times: Vec<f32> = path.keyframes.iter().map(|k| k.time).collect();
+        assert_eq!(times, [0.0, 1.5, 2.0, 4.0]);
+        assert_eq!(CameraPath::from_text(&path.to_text()).unwrap(), path);
+    }
+}
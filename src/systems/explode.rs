@@ -0,0 +1,91 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/explode.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de vue éclatée du logo
+//!
+//! Chaque partie s'écarte selon sa direction radiale et en profondeur :
+//! - Touche X : Éclater / réassembler (animé)
+//! - Page haut / Page bas : Ajuster le facteur d'éclatement
+
+use bevy::prelude::*;
+use crate::config;
+use crate::systems::part_motion::{PartPivot, RestTransform};
+use crate::systems::setup::LogoPart;
+
+/// Ressource contenant l'état de la vue éclatée
+#[derive(Resource, Default)]
+pub struct ExplodeState {
+    /// Facteur courant (0 : assemblé, 1 : éclaté)
+    pub factor: f32,
+    /// Facteur visé, atteint progressivement
+    pub target: f32,
+}
+
+/// Direction d'éclatement d'une partie (espace du parent, non normalisée en Z)
+#[derive(Component, Clone, Copy)]
+pub struct ExplodeDirection(pub Vec3);
+
+/// Couche en profondeur de chaque catégorie de partie
+fn depth_layer(part: &LogoPart) -> f32 {
+    match part {
+        LogoPart::Ring => 0.0,
+        LogoPart::ExteriorTriangle(_) => -0.5,
+        LogoPart::InteriorTriangle(_) => 1.0,
+        LogoPart::SmallCircle(_) => 1.5,
+        LogoPart::RPart(_) => 2.5,
+    }
+}
+
+/// Calcule la direction d'éclatement : radiale (XY) depuis le centre du logo
+pub fn explode_direction(part: &LogoPart, centroid: Vec3) -> Vec3 {
+    let radial = centroid.truncate().normalize_or_zero();
+    radial.extend(0.0) * config::EXPLODE_DISTANCE + Vec3::Z * depth_layer(part) * config::EXPLODE_DEPTH
+}
+
+/// Calcule la direction d'éclatement des nouvelles parties
+pub fn init_explode_direction_system(
+    mut commands: Commands,
+    query: Query<(Entity, &LogoPart, &RestTransform, &PartPivot), Without<ExplodeDirection>>,
+) {
+    for (entity, part, rest, pivot) in query.iter() {
+        let centroid = rest.0.transform_point(pivot.0);
+        commands.entity(entity).insert(ExplodeDirection(explode_direction(part, centroid)));
+    }
+}
+
+/// Système de vue éclatée
+pub fn explode_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<ExplodeState>,
+    mut query: Query<(&ExplodeDirection, &mut Transform)>,
+) {
+    // === COMMANDES ===
+    if keyboard.just_pressed(KeyCode::KeyX) {
+        state.target = if state.target > 0.5 { 0.0 } else { 1.0 };
+    }
+    if keyboard.just_pressed(KeyCode::PageUp) {
+        state.target = (state.factor + 0.1).min(1.0);
+        state.factor = state.target;
+    }
+    if keyboard.just_pressed(KeyCode::PageDown) {
+        state.target = (state.factor - 0.1).max(0.0);
+        state.factor = state.target;
+    }
+
+    // === PROGRESSION VERS LE FACTEUR VISÉ ===
+    let step = time.delta_secs() / config::EXPLODE_DURATION;
+    let delta = state.target - state.factor;
+    state.factor += delta.clamp(-step, step);
+
+    if state.factor <= 0.0 {
+        return;
+    }
+
+    // Adoucissement aux extrémités du mouvement
+    let eased = state.factor * state.factor * (3.0 - 2.0 * state.factor);
+    for (direction, mut transform) in query.iter_mut() {
+        transform.translation += direction.0 * eased;
+    }
+}
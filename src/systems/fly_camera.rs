@@ -0,0 +1,159 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/fly_camera.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de caméra libre (vol à la première personne)
+//!
+//! Permet d'inspecter les faces arrière et l'épaisseur des pièces :
+//! - Touche C : Basculer entre caméra orbitale et caméra libre
+//! - ZQSD / WASD : Avancer, reculer, gauche, droite
+//! - Espace / Ctrl : Monter / descendre (le long de l'axe vertical de l'orbite)
+//! - Maj : Accélérer
+//! - Clic gauche + déplacement souris : Regarder autour
+
+use bevy::prelude::*;
+use bevy::input::mouse::MouseMotion;
+use crate::config;
+use crate::systems::camera::{cartesian_to_spherical, up_basis, OrbitCamera};
+
+/// Composant de caméra libre, ajouté à côté de `OrbitCamera`
+#[derive(Component)]
+pub struct FlyCamera {
+    /// Mode libre actif (la caméra orbitale est alors suspendue)
+    pub active: bool,
+    /// Angle horizontal du regard en radians
+    pub yaw: f32,
+    /// Angle vertical du regard en radians
+    pub pitch: f32,
+    /// Vitesse de déplacement (unités par seconde)
+    pub speed: f32,
+    /// Multiplicateur de vitesse avec Maj
+    pub boost: f32,
+    /// Sensibilité du regard (radians par pixel de souris)
+    pub sensitivity: f32,
+    /// Angle vertical maximal du regard, dans les deux sens (radians)
+    pub max_pitch: f32,
+}
+
+impl Default for FlyCamera {
+    fn default() -> Self {
+        Self {
+            active: false,
+            yaw: 0.0,
+            pitch: 0.0,
+            speed: config::FLY_SPEED,
+            boost: config::FLY_BOOST,
+            sensitivity: config::FLY_SENSITIVITY,
+            max_pitch: config::FLY_MAX_PITCH,
+        }
+    }
+}
+
+/// Orientation du regard pour des angles mesurés autour de l'axe vertical `up`
+pub fn fly_rotation(yaw: f32, pitch: f32, up: Vec3) -> Quat {
+    up_basis(up) * Quat::from_euler(EulerRot::YXZ, yaw, pitch, 0.0)
+}
+
+/// Angles (lacet, tangage) d'une orientation autour de l'axe vertical `up`
+pub fn fly_angles(rotation: Quat, up: Vec3) -> (f32, f32) {
+    let (yaw, pitch, _) = (up_basis(up).inverse() * rotation).to_euler(EulerRot::YXZ);
+    (yaw, pitch)
+}
+
+/// Système de caméra libre
+///
+/// Au passage en mode libre, le regard reprend l'orientation courante. Au
+/// retour en mode orbital, le point focal est replacé dans l'axe du regard,
+/// à la même distance qu'avant, pour éviter tout saut de la caméra.
+pub fn fly_camera_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    mut query: Query<(&mut FlyCamera, &mut OrbitCamera, &mut Transform)>,
+) {
+    for (mut fly, mut orbit, mut transform) in query.iter_mut() {
+
+        // === BASCULE ORBITE / VOL LIBRE ===
+        if keyboard.just_pressed(KeyCode::KeyC) {
+            fly.active = !fly.active;
+            if fly.active {
+                let (yaw, pitch) = fly_angles(transform.rotation, orbit.up);
+                fly.yaw = yaw;
+                fly.pitch = pitch;
+            } else {
+                let distance = transform.translation.distance(orbit.focus)
+                    .clamp(orbit.min_distance, orbit.max_distance);
+                orbit.focus = transform.translation + transform.forward() * distance;
+
+                let (yaw, pitch, distance) =
+                    cartesian_to_spherical(transform.translation - orbit.focus, orbit.up);
+                orbit.yaw = yaw;
+                orbit.pitch = pitch;
+                orbit.distance = distance;
+            }
+        }
+
+        if !fly.active {
+            continue;
+        }
+
+        // === REGARD AVEC SOURIS ===
+        if mouse_button.pressed(MouseButton::Left) {
+            for motion in mouse_motion.read() {
+                let sensitivity = fly.sensitivity;
+                fly.yaw -= motion.delta.x * sensitivity;
+                fly.pitch -= motion.delta.y * sensitivity;
+                fly.pitch = fly.pitch.clamp(-fly.max_pitch, fly.max_pitch);
+            }
+        } else {
+            mouse_motion.clear();
+        }
+        transform.rotation = fly_rotation(fly.yaw, fly.pitch, orbit.up);
+
+        // === DÉPLACEMENT AU CLAVIER ===
+        let mut direction = Vec3::ZERO;
+        if keyboard.pressed(KeyCode::KeyW) {
+            direction += *transform.forward();
+        }
+        if keyboard.pressed(KeyCode::KeyS) {
+            direction -= *transform.forward();
+        }
+        if keyboard.pressed(KeyCode::KeyD) {
+            direction += *transform.right();
+        }
+        if keyboard.pressed(KeyCode::KeyA) {
+            direction -= *transform.right();
+        }
+        let up = orbit.up.normalize_or(Vec3::Y);
+        if keyboard.pressed(KeyCode::Space) {
+            direction += up;
+        }
+        if keyboard.pressed(KeyCode::ControlLeft) {
+            direction -= up;
+        }
+
+        let mut speed = fly.speed;
+        if keyboard.pressed(KeyCode::ShiftLeft) || keyboard.pressed(KeyCode::ShiftRight) {
+            speed *= fly.boost;
+        }
+        transform.translation += direction.normalize_or_zero() * speed * time.delta_secs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn look_angles_follow_the_orbit_up_axis() {
+        let up = Vec3::Z;
+        // Regard horizontal : le haut de la caméra est l'axe vertical choisi
+        let level = fly_rotation(0.7, 0.0, up);
+        assert!((level * Vec3::Y).abs_diff_eq(up, 1e-5));
+        assert!((level * Vec3::NEG_Z).dot(up).abs() < 1e-5);
+
+        let (yaw, pitch) = fly_angles(fly_rotation(0.7, -0.4, up), up);
+        assert!((yaw - 0.7).abs() < 1e-4 && (pitch + 0.4).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,144 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/framing.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de cadrage automatique de la caméra
+//!
+//! Ce module calcule la sphère englobante du logo (ou d'une partie) et
+//! ajuste la caméra orbitale en conséquence :
+//! - Distance, limites de zoom et plans de coupe
+//! - Touche F : Cadrer tout le logo
+//! - Touche Tab : Sélectionner la partie suivante
+//! - Touche G : Cadrer la partie sélectionnée
+
+use bevy::prelude::*;
+use bevy::window::{PrimaryWindow, WindowResized};
+use crate::{config, geometry};
+use crate::systems::camera::{build_projection, perspective_visible_height, OrbitCamera, RotatingObject};
+
+/// Événement demandant de recadrer tout le logo
+#[derive(Event)]
+pub struct FrameLogoRequest;
+
+/// Ressource contenant la partie du logo actuellement sélectionnée
+#[derive(Resource, Default)]
+pub struct SelectedPart(pub Option<Entity>);
+
+/// Distance à laquelle une sphère tient entièrement dans le champ de vision
+///
+/// Le demi-angle le plus petit (vertical ou horizontal selon le ratio) est
+/// celui qui limite le cadrage.
+pub fn framing_distance(radius: f32, fov: f32, aspect_ratio: f32) -> f32 {
+    let half_vertical = fov / 2.0;
+    let half_horizontal = ((fov / 2.0).tan() * aspect_ratio).atan();
+    let half_angle = half_vertical.min(half_horizontal);
+    radius * config::FRAMING_MARGIN / half_angle.sin()
+}
+
+/// Ajuste la caméra orbitale pour cadrer une sphère (centre, rayon)
+pub fn frame_sphere(orbit: &mut OrbitCamera, center: Vec3, radius: f32, aspect_ratio: f32) {
+    let distance = framing_distance(radius, orbit.fov, aspect_ratio);
+
+    orbit.focus = center;
+    orbit.distance = distance;
+    orbit.min_distance = distance * 0.4;
+    orbit.max_distance = distance * 3.0;
+    orbit.ortho_scale = perspective_visible_height(distance, orbit.fov);
+
+    // Les plans de coupe couvrent toute la plage de zoom autorisée
+    orbit.near = ((orbit.min_distance - radius) * 0.5).max(0.1);
+    orbit.far = orbit.max_distance + radius * 2.0;
+}
+
+/// Calcule la sphère englobante d'une partie dans l'espace du parent
+fn part_points(mesh: &Mesh, transform: &Transform) -> Vec<Vec3> {
+    geometry::mesh_positions(mesh)
+        .into_iter()
+        .map(|p| transform.transform_point(p))
+        .collect()
+}
+
+/// Système de cadrage automatique
+///
+/// Cadre tout le logo au démarrage, au redimensionnement de la fenêtre et
+/// sur la touche F ; cadre la partie sélectionnée sur la touche G.
+#[allow(clippy::too_many_arguments)]
+pub fn camera_framing_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut resized: EventReader<WindowResized>,
+    mut requests: EventReader<FrameLogoRequest>,
+    mut selected: ResMut<SelectedPart>,
+    mut framed: Local<bool>,
+    meshes: Res<Assets<Mesh>>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    logos: Query<(&Transform, &Children), With<RotatingObject>>,
+    parts: Query<(&Mesh3d, &Transform)>,
+    mut cameras: Query<(&mut OrbitCamera, &mut Projection, &Camera)>,
+) {
+    // === SÉLECTION DE LA PARTIE SUIVANTE ===
+    if keyboard.just_pressed(KeyCode::Tab) {
+        let all_parts: Vec<Entity> = logos
+            .iter()
+            .flat_map(|(_, children)| children.iter())
+            .collect();
+        if !all_parts.is_empty() {
+            let next = match selected.0.and_then(|e| all_parts.iter().position(|p| *p == e)) {
+                Some(index) => (index + 1) % all_parts.len(),
+                None => 0,
+            };
+            selected.0 = Some(all_parts[next]);
+        }
+    }
+
+    // Les deux lecteurs sont vidés à chaque image, même sans cadrage
+    let resized = resized.read().count() > 0;
+    let requested = requests.read().count() > 0;
+    let frame_all = !*framed || resized || requested || keyboard.just_pressed(KeyCode::KeyF);
+    let frame_selected = keyboard.just_pressed(KeyCode::KeyG) && selected.0.is_some();
+    if !frame_all && !frame_selected {
+        return;
+    }
+
+    // === CALCUL DE LA SPHÈRE ENGLOBANTE ===
+    let mut points = Vec::new();
+    for (logo_transform, children) in logos.iter() {
+        for child in children.iter() {
+            if frame_selected && !frame_all && selected.0 != Some(child) {
+                continue;
+            }
+            if let Ok((mesh_handle, part_transform)) = parts.get(child)
+                && let Some(mesh) = meshes.get(&mesh_handle.0)
+            {
+                let world = logo_transform.mul_transform(*part_transform);
+                points.extend(part_points(mesh, &world));
+            }
+        }
+    }
+    if points.is_empty() {
+        return;
+    }
+    let (center, radius) = geometry::calculate_bounding_sphere(&points);
+
+    // === APPLICATION À CHAQUE CAMÉRA ===
+    let window_size = windows.single().map(|w| w.size()).unwrap_or(Vec2::new(16.0, 9.0));
+    for (mut orbit, mut projection, camera) in cameras.iter_mut() {
+        let size = camera.logical_viewport_size().unwrap_or(window_size);
+        let aspect_ratio = if size.y > 0.0 { size.x / size.y } else { 1.0 };
+
+        if frame_all {
+            frame_sphere(&mut orbit, center, radius, aspect_ratio);
+            orbit.home_distance = orbit.distance;
+            orbit.home_focus = orbit.focus;
+        } else {
+            // Cadrage d'une partie : on conserve la possibilité de dézoomer sur tout le logo
+            let max_distance = orbit.max_distance;
+            let far = orbit.far;
+            frame_sphere(&mut orbit, center, radius, aspect_ratio);
+            orbit.max_distance = orbit.max_distance.max(max_distance);
+            orbit.far = orbit.far.max(far);
+        }
+        *projection = build_projection(&orbit);
+    }
+
+    *framed = true;
+}
@@ -0,0 +1,219 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/intro.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'animation d'apparition du logo
+//!
+//! Au démarrage, les parties apparaissent dans l'ordre :
+//! 1. L'anneau se dessine en faisant croître son arc
+//! 2. Les 36 triangles extérieurs surgissent l'un après l'autre
+//! 3. Les triangles intérieurs et leurs petits cercles glissent en place
+//! 4. Le R s'extrude depuis une profondeur nulle
+//!
+//! - Touche I : Rejouer l'apparition
+
+use bevy::prelude::*;
+use std::f32::consts::PI;
+use crate::{config, geometry};
+use crate::timeline::Easing;
+use crate::systems::morph::MorphState;
+use crate::systems::part_motion::{apply_about_pivot, PartPivot};
+use crate::systems::setup::LogoPart;
+
+/// Distance parcourue par les triangles intérieurs en glissant
+const SLIDE_DISTANCE: f32 = 150.0;
+
+/// Ressource contenant l'état de l'apparition
+#[derive(Resource)]
+pub struct IntroState {
+    /// Temps écoulé depuis le début de l'apparition
+    pub time: f32,
+    /// Durée totale de l'apparition
+    pub duration: f32,
+    pub playing: bool,
+    /// Mesh partagé de l'anneau et mesh propre où l'arc est dessiné
+    ///
+    /// Le mesh de l'anneau vient du cache (et sert de niveau de détail 0) :
+    /// l'arc croissant est dessiné dans un mesh à part, et le handle partagé
+    /// est remis en place à la fin de l'apparition.
+    ring_meshes: Option<(Handle<Mesh>, Handle<Mesh>)>,
+}
+
+impl Default for IntroState {
+    fn default() -> Self {
+        Self {
+            time: 0.0,
+            duration: config::INTRO_DURATION,
+            playing: true,
+            ring_meshes: None,
+        }
+    }
+}
+
+/// Progression (0 à 1) d'une étape occupant la fraction [start, end] de l'apparition
+pub fn phase_progress(t: f32, start: f32, end: f32) -> f32 {
+    ((t - start) / (end - start)).clamp(0.0, 1.0)
+}
+
+/// Progression d'un élément d'une étape décalée (stagger)
+///
+/// Chaque élément dure `item_fraction` de l'étape ; les départs sont
+/// répartis régulièrement sur le reste.
+pub fn staggered_progress(t: f32, start: f32, end: f32, index: usize, count: usize, item_fraction: f32) -> f32 {
+    let phase = phase_progress(t, start, end);
+    let spread = 1.0 - item_fraction;
+    let offset = if count > 1 { spread * index as f32 / (count - 1) as f32 } else { 0.0 };
+    ((phase - offset) / item_fraction).clamp(0.0, 1.0)
+}
+
+/// Progression de chaque partie à l'instant normalisé `t` (0 à 1)
+fn part_progress(part: &LogoPart, t: f32) -> f32 {
+    match part {
+        LogoPart::Ring => phase_progress(t, 0.0, 0.3),
+        LogoPart::ExteriorTriangle(i) => {
+            staggered_progress(t, 0.25, 0.6, *i, config::EXTERIOR_TRIANGLES_COUNT, 0.2)
+        }
+        LogoPart::InteriorTriangle(i) | LogoPart::SmallCircle(i) => {
+            staggered_progress(t, 0.55, 0.8, *i, config::INTERIOR_TRIANGLES_COUNT, 0.5)
+        }
+        LogoPart::RPart(_) => phase_progress(t, 0.75, 1.0),
+    }
+}
+
+/// Système d'apparition du logo
+pub fn intro_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<IntroState>,
+    morph: Res<MorphState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut query: Query<(&LogoPart, &mut Mesh3d, &PartPivot, &mut Transform, &mut Visibility)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyI) {
+        state.time = 0.0;
+        state.playing = true;
+    }
+    if !state.playing {
+        return;
+    }
+
+    state.time = (state.time + time.delta_secs()).min(state.duration);
+    let t = if state.duration > 0.0 { state.time / state.duration } else { 1.0 };
+
+    let r_parts = geometry::get_all_r_parts();
+    for (part, mut mesh_handle, pivot, mut transform, mut visibility) in query.iter_mut() {
+        let progress = part_progress(part, t);
+        visibility.set_if_neq(if progress > 0.0 { Visibility::Inherited } else { Visibility::Hidden });
+
+        match part {
+            // === ANNEAU : ARC CROISSANT ===
+            LogoPart::Ring => {
+                let arc = geometry::create_3d_ring_arc_mesh(
+                    config::CIRCLE_RADIUS,
+                    config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS,
+                    config::DEPTH,
+                    config::CIRCLE_SEGMENTS,
+                    2.0 * PI * Easing::EaseInOut.apply(progress),
+                );
+                let own = match &state.ring_meshes {
+                    Some((_, own)) => {
+                        if let Some(mesh) = meshes.get_mut(own) {
+                            *mesh = arc;
+                        }
+                        own.clone()
+                    }
+                    None => {
+                        let own = meshes.add(arc);
+                        state.ring_meshes = Some((mesh_handle.0.clone(), own.clone()));
+                        own
+                    }
+                };
+                if mesh_handle.0 != own {
+                    mesh_handle.0 = own;
+                }
+            }
+            // === TRIANGLES EXTÉRIEURS : SURGISSEMENT ===
+            LogoPart::ExteriorTriangle(_) => {
+                let scale = Easing::EaseOutBack.apply(progress);
+                apply_about_pivot(&mut transform, pivot.0, Quat::IDENTITY, Vec3::splat(scale));
+            }
+            // === TRIANGLES INTÉRIEURS ET PETITS CERCLES : GLISSEMENT ===
+            LogoPart::InteriorTriangle(_) | LogoPart::SmallCircle(_) => {
+                let radial = transform.transform_point(pivot.0).truncate().normalize_or_zero();
+                let remaining = 1.0 - Easing::EaseOut.apply(progress);
+                transform.translation += radial.extend(0.0) * SLIDE_DISTANCE * remaining;
+            }
+            // === R : EXTRUSION ===
+            LogoPart::RPart(name) => {
+                let depth = (config::DEPTH * Easing::EaseInOut.apply(progress)).max(0.01);
+                // Contour courant du morphing s'il a été lancé, sinon celui du R
+                let outline = morph.outline(name).or_else(|| {
+                    r_parts.iter().find(|p| p.name == *name).map(|p| p.points.clone())
+                });
+                if let (Some(outline), Some(mesh)) = (outline, meshes.get_mut(&mesh_handle.0)) {
+                    *mesh = geometry::create_3d_polygon_mesh(&outline, depth);
+                }
+            }
+        }
+    }
+
+    if state.time >= state.duration {
+        state.playing = false;
+        // === RESTAURATION DU MESH PARTAGÉ DE L'ANNEAU ===
+        if let Some((shared, own)) = state.ring_meshes.take() {
+            for (part, mut mesh_handle, ..) in query.iter_mut() {
+                if matches!(part, LogoPart::Ring) {
+                    mesh_handle.0 = shared.clone();
+                }
+            }
+            meshes.remove(&own);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::asset::AssetPlugin;
+
+    #[test]
+    fn ring_arc_leaves_the_shared_mesh_alone() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<MorphState>()
+            .insert_resource(IntroState { duration: 1000.0, ..default() })
+            .add_systems(Update, intro_system);
+
+        let ring = || geometry::create_3d_ring_mesh(
+            config::CIRCLE_RADIUS,
+            config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS,
+            config::DEPTH,
+            config::CIRCLE_SEGMENTS,
+        );
+        let vertex_count = ring().count_vertices();
+        let shared = app.world_mut().resource_mut::<Assets<Mesh>>().add(ring());
+        let entity = app.world_mut().spawn((
+            LogoPart::Ring,
+            Mesh3d(shared.clone()),
+            PartPivot(Vec3::ZERO),
+            Transform::default(),
+            Visibility::default(),
+        )).id();
+
+        // Pendant l'apparition, l'arc est dessiné dans un mesh propre
+        app.update();
+        let during = app.world().get::<Mesh3d>(entity).unwrap().0.clone();
+        assert_ne!(during, shared);
+        let meshes = app.world().resource::<Assets<Mesh>>();
+        assert_eq!(meshes.get(&shared).unwrap().count_vertices(), vertex_count);
+
+        // À la fin, le handle partagé est remis en place et le mesh propre libéré
+        let mut state = app.world_mut().resource_mut::<IntroState>();
+        state.duration = state.time;
+        app.update();
+        assert_eq!(app.world().get::<Mesh3d>(entity).unwrap().0, shared);
+        assert!(app.world().resource::<Assets<Mesh>>().get(&during).is_none());
+    }
+}
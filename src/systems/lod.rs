@@ -0,0 +1,176 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/lod.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de niveaux de détail (LOD)
+//!
+//! L'anneau et les petits cercles existent en plusieurs finesses
+//! (`config::LOD_*_SEGMENTS`). Le niveau est choisi d'après la taille
+//! projetée à l'écran : un niveau suffit tant que l'écart entre ses cordes
+//! et le cercle reste sous `config::LOD_MAX_ERROR_PX`. Une marge
+//! d'hystérésis évite les changements incessants autour d'un seuil.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::cache::{MeshCache, MeshKey};
+use crate::systems::intro::IntroState;
+use crate::systems::setup::LogoPart;
+
+/// Niveaux de détail d'un mesh
+#[derive(Component, Clone, Debug)]
+pub struct MeshLod {
+    /// Meshes, du plus fin au plus grossier
+    pub levels: Vec<Handle<Mesh>>,
+    /// Diamètre à l'écran (pixels) sous lequel le niveau suivant suffit
+    pub thresholds: Vec<f32>,
+    /// Sphère englobante (espace local)
+    pub center: Vec3,
+    pub radius: f32,
+    /// Niveau affiché
+    pub current: usize,
+}
+
+impl MeshLod {
+    /// Seuils déduits du nombre de segments de chaque niveau
+    pub fn new(levels: Vec<Handle<Mesh>>, segments: &[usize], center: Vec3, radius: f32) -> Self {
+        let thresholds = segments[1..]
+            .iter()
+            .map(|&segments| lod_max_diameter(segments, config::LOD_MAX_ERROR_PX))
+            .collect();
+        Self { levels, thresholds, center, radius, current: 0 }
+    }
+}
+
+/// Plus grand diamètre à l'écran (pixels) qu'un cercle de `segments`
+/// segments peut avoir sans que ses cordes s'écartent de plus de `max_error`
+pub fn lod_max_diameter(segments: usize, max_error: f32) -> f32 {
+    // Rayon pour lequel l'écart de corde atteint `max_error`
+    2.0 * max_error / geometry::chord_error(1.0, segments)
+}
+
+/// Diamètre à l'écran (pixels) d'une sphère de rayon `radius` à `distance`
+pub fn projected_diameter(radius: f32, distance: f32, projection: &Projection, viewport_height: f32) -> f32 {
+    let visible_height = match projection {
+        Projection::Perspective(perspective) => 2.0 * distance.max(perspective.near) * (perspective.fov / 2.0).tan(),
+        Projection::Orthographic(orthographic) => orthographic.area.height(),
+        _ => return viewport_height,
+    };
+    2.0 * radius / visible_height.max(f32::EPSILON) * viewport_height
+}
+
+/// Choisit le niveau de détail pour un diamètre `size` à l'écran
+///
+/// Les seuils sont décroissants. Pour changer de niveau, la taille doit
+/// dépasser le seuil d'une marge `hysteresis` (fraction du seuil).
+pub fn select_lod(current: usize, size: f32, thresholds: &[f32], hysteresis: f32) -> usize {
+    let level_for = |factor: f32| thresholds.iter().filter(|&&t| size <= t * factor).count();
+    let coarser = level_for(1.0 - hysteresis);
+    if coarser > current {
+        return coarser;
+    }
+    let finer = level_for(1.0 + hysteresis);
+    if finer < current {
+        return finer;
+    }
+    current
+}
+
+/// Crée les niveaux de détail de l'anneau et des petits cercles
+pub fn init_lod_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mesh_cache: ResMut<MeshCache>,
+    query: Query<(Entity, &LogoPart), Added<LogoPart>>,
+) {
+    for (entity, part) in query.iter() {
+        let lod = match part {
+            LogoPart::Ring => {
+                let (outer, inner) = (config::CIRCLE_RADIUS, config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS);
+                let levels = config::LOD_RING_SEGMENTS
+                    .iter()
+                    .map(|&segments| {
+                        mesh_cache.get_or_add(&mut meshes, MeshKey::ring(outer, inner, config::DEPTH, segments), || {
+                            geometry::create_3d_ring_mesh(outer, inner, config::DEPTH, segments)
+                        })
+                    })
+                    .collect();
+                MeshLod::new(levels, &config::LOD_RING_SEGMENTS, Vec3::ZERO, outer)
+            }
+            LogoPart::SmallCircle(_) => {
+                let radius = config::SMALL_CIRCLE_RADIUS;
+                let levels = config::LOD_CIRCLE_SEGMENTS
+                    .iter()
+                    .map(|&segments| {
+                        mesh_cache.get_or_add(&mut meshes, MeshKey::cylinder(radius, config::DEPTH, segments), || {
+                            geometry::create_3d_cylinder_mesh(radius, config::DEPTH, segments)
+                        })
+                    })
+                    .collect();
+                MeshLod::new(levels, &config::LOD_CIRCLE_SEGMENTS, Vec3::ZERO, radius)
+            }
+            _ => continue,
+        };
+        commands.entity(entity).insert(lod);
+    }
+}
+
+/// Choisit et applique le niveau de détail de chaque mesh
+///
+/// La taille retenue est la plus grande parmi les vues actives. Pendant
+/// l'apparition, les meshes sont laissés tels quels : l'anneau y est dessiné
+/// dans un mesh propre à l'animation.
+pub fn lod_system(
+    intro: Res<IntroState>,
+    cameras: Query<(&Camera, &GlobalTransform, &Projection)>,
+    mut query: Query<(&mut MeshLod, &mut Mesh3d, &GlobalTransform)>,
+) {
+    if intro.playing {
+        return;
+    }
+    for (mut lod, mut mesh, transform) in query.iter_mut() {
+        let center = transform.transform_point(lod.center);
+        let radius = lod.radius * transform.scale().abs().max_element();
+        let size = cameras
+            .iter()
+            .filter(|(camera, ..)| camera.is_active)
+            .filter_map(|(camera, camera_transform, projection)| {
+                let height = camera.logical_viewport_size()?.y;
+                Some(projected_diameter(radius, camera_transform.translation().distance(center), projection, height))
+            })
+            .fold(0.0, f32::max);
+        let level = select_lod(lod.current, size, &lod.thresholds, config::LOD_HYSTERESIS);
+
+        let level = level.min(lod.levels.len() - 1);
+        if level != lod.current || mesh.0 != lod.levels[level] {
+            lod.current = level;
+            mesh.0 = lod.levels[level].clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finer_segments_allow_larger_circles() {
+        let sizes: Vec<f32> = [8, 16, 32, 64].iter().map(|&n| lod_max_diameter(n, 0.5)).collect();
+        assert!(sizes.windows(2).all(|pair| pair[0] < pair[1]));
+        // Un cercle de 16 segments tient jusqu'à environ 52 pixels
+        assert!((sizes[1] - 51.5).abs() < 1.0);
+    }
+
+    #[test]
+    fn level_changes_with_hysteresis() {
+        let thresholds = [200.0, 50.0];
+        assert_eq!(select_lod(0, 400.0, &thresholds, 0.1), 0);
+        assert_eq!(select_lod(0, 100.0, &thresholds, 0.1), 1);
+        assert_eq!(select_lod(0, 10.0, &thresholds, 0.1), 2);
+
+        // Autour du seuil de 200 pixels, le niveau courant est conservé
+        assert_eq!(select_lod(0, 190.0, &thresholds, 0.1), 0);
+        assert_eq!(select_lod(1, 210.0, &thresholds, 0.1), 1);
+        assert_eq!(select_lod(0, 170.0, &thresholds, 0.1), 1);
+        assert_eq!(select_lod(1, 230.0, &thresholds, 0.1), 0);
+    }
+}
@@ -0,0 +1,156 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/logo_wall.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de mur de logos (scène de charge)
+//!
+//! Des centaines de logos, chacun avec sa phase de rotation et sa teinte :
+//! - Touche Q : Afficher / retirer le mur de logos
+//!
+//! Tous les logos partagent les mêmes meshes fusionnés (un par niveau de
+//! détail) et un petit nombre de matériaux (un par teinte) : Bevy peut les
+//! regrouper en lots instanciés.
+//! Un seul système anime l'ensemble. Mesure sans fenêtre (animation et
+//! propagation des transforms seulement, sans extraction du rendu) :
+//! `cargo run --release --example logo_wall_bench -- <N>`.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::cache::MaterialCache;
+use crate::physics::DeterministicRng;
+use crate::systems::lod::MeshLod;
+use crate::systems::setup::logo_parts;
+
+/// Racine du mur de logos
+#[derive(Component)]
+pub struct LogoWall;
+
+/// État d'animation d'un logo du mur
+#[derive(Component, Clone, Copy)]
+pub struct WallInstance {
+    /// Angle de départ (radians)
+    pub phase: f32,
+    /// Vitesse de rotation (radians par seconde)
+    pub speed: f32,
+}
+
+/// Construit le logo complet en un seul mesh à couleurs par sommet
+///
+/// Les parties viennent de `logo_parts`, comme la scène principale ;
+/// `ring_segments` et `circle_segments` fixent la finesse de l'anneau et
+/// des petits cercles.
+pub fn build_logo_mesh(ring_segments: usize, circle_segments: usize) -> Mesh {
+    let parts: Vec<_> = logo_parts()
+        .into_iter()
+        .map(|spec| (spec.shape.mesh(ring_segments, circle_segments), spec.transform, spec.material().base_color.to_linear()))
+        .collect();
+    let inputs: Vec<_> = parts.iter().map(|(mesh, transform, color)| (mesh, *transform, *color)).collect();
+    geometry::merge_meshes(&inputs).0
+}
+
+/// Crée un mur de `count` logos en grille, centré sur `origin`
+///
+/// Renvoie l'entité racine ; ses enfants portent `WallInstance`.
+pub fn spawn_logo_wall(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    cache: &mut MaterialCache,
+    count: usize,
+    origin: Vec3,
+) -> Entity {
+    let levels: Vec<Handle<Mesh>> = config::LOD_RING_SEGMENTS
+        .iter()
+        .zip(config::LOD_CIRCLE_SEGMENTS)
+        .map(|(&ring, circle)| meshes.add(build_logo_mesh(ring, circle)))
+        .collect();
+    let lod = MeshLod::new(
+        levels.clone(),
+        &config::LOD_RING_SEGMENTS,
+        Vec3::ZERO,
+        config::CIRCLE_RADIUS + config::SMALL_TRIANGLE_SIDE,
+    );
+    // Teintes partagées : le blanc multiplié par les couleurs par sommet
+    let tints: Vec<Handle<StandardMaterial>> = (0..config::LOGO_WALL_TINTS)
+        .map(|i| {
+            let hue = i as f32 * 360.0 / config::LOGO_WALL_TINTS as f32;
+            cache.get_or_add(
+                materials,
+                StandardMaterial {
+                    base_color: Color::hsl(hue, 0.6, 0.85),
+                    metallic: 0.3,
+                    perceptual_roughness: 0.5,
+                    ..default()
+                },
+            )
+        })
+        .collect();
+
+    let columns = (count as f32).sqrt().ceil().max(1.0) as usize;
+    let rows = count.div_ceil(columns);
+    let offset = Vec2::new(columns as f32 - 1.0, rows as f32 - 1.0) * config::LOGO_WALL_SPACING / 2.0;
+    let mut rng = DeterministicRng::new(7);
+
+    let root = commands.spawn((LogoWall, Transform::from_translation(origin), Visibility::default())).id();
+    for i in 0..count {
+        let cell = Vec2::new((i % columns) as f32, (i / columns) as f32) * config::LOGO_WALL_SPACING - offset;
+        let instance = WallInstance {
+            phase: rng.range(0.0, std::f32::consts::TAU),
+            speed: rng.range(0.2, 1.0),
+        };
+        let child = commands
+            .spawn((
+                Mesh3d(levels[0].clone()),
+                MeshMaterial3d(tints[i % tints.len()].clone()),
+                Transform::from_xyz(cell.x, cell.y, 0.0),
+                instance,
+                lod.clone(),
+            ))
+            .id();
+        commands.entity(root).add_child(child);
+    }
+    root
+}
+
+/// Affiche ou retire le mur de logos
+pub fn logo_wall_toggle_system(
+    mut commands: Commands,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut cache: ResMut<MaterialCache>,
+    walls: Query<Entity, With<LogoWall>>,
+) {
+    if !keyboard.just_pressed(KeyCode::KeyQ) {
+        return;
+    }
+    if walls.is_empty() {
+        let origin = Vec3::new(0.0, 0.0, -1500.0);
+        spawn_logo_wall(&mut commands, &mut meshes, &mut materials, &mut cache, config::LOGO_WALL_COUNT, origin);
+        println!("🧱 Mur de {} logos", config::LOGO_WALL_COUNT);
+    } else {
+        for wall in walls.iter() {
+            commands.entity(wall).despawn();
+        }
+    }
+}
+
+/// Anime tous les logos du mur (rotation propre à chacun)
+pub fn logo_wall_animation_system(time: Res<Time>, mut query: Query<(&WallInstance, &mut Transform)>) {
+    let elapsed = time.elapsed_secs();
+    for (instance, mut transform) in query.iter_mut() {
+        transform.rotation = Quat::from_rotation_y(instance.phase + elapsed * instance.speed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wall_mesh_holds_every_logo_part() {
+        let (ring, circle) = (config::LOD_RING_SEGMENTS[1], config::LOD_CIRCLE_SEGMENTS[1]);
+        let expected: usize = logo_parts().iter().map(|spec| spec.shape.mesh(ring, circle).count_vertices()).sum();
+        assert_eq!(build_logo_mesh(ring, circle).count_vertices(), expected);
+    }
+}
@@ -0,0 +1,106 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/material_animation.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'animation des matériaux
+//!
+//! Les paramètres viennent de `LogoSpec::material_animation` :
+//! - Rotation des teintes arc-en-ciel autour de l'anneau
+//! - Pulsation émissive du R
+//! - Fondu de l'opacité des petits cercles
+//!
+//! - Touche U : Activer / désactiver l'animation des matériaux
+
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+use crate::palette::Palette;
+use crate::spec::{LogoSpec, MaterialAnimationSpec};
+use crate::systems::palette::{part_color, CvdSimulation};
+use crate::systems::picking::{highlighted_emissive, HoverHighlight};
+use crate::systems::setup::LogoPart;
+
+/// Ressource contenant l'état de l'animation des matériaux
+#[derive(Resource, Default)]
+pub struct MaterialAnimation {
+    pub enabled: bool,
+    /// Temps écoulé depuis l'activation
+    pub time: f32,
+}
+
+/// Couleur d'un triangle arc-en-ciel, décalée de `hue_offset` degrés
+pub fn rainbow_color(index: usize, hue_offset: f32, spec: &MaterialAnimationSpec) -> Color {
+    let hue = (index as f32 * spec.hue_step + hue_offset).rem_euclid(360.0);
+    Color::hsl(hue, spec.rainbow_saturation, spec.rainbow_lightness)
+}
+
+/// Intensité (0 à 1) de la pulsation du R à l'instant `time`
+pub fn pulse_intensity(time: f32, frequency: f32) -> f32 {
+    0.5 - 0.5 * (time * frequency * TAU).cos()
+}
+
+/// Opacité des petits cercles à l'instant `time`
+pub fn circle_alpha(time: f32, spec: &MaterialAnimationSpec) -> f32 {
+    let phase = 0.5 + 0.5 * (time / spec.circle_fade_period.max(f32::EPSILON) * TAU).cos();
+    spec.circle_alpha_min + (spec.circle_alpha_max - spec.circle_alpha_min) * phase
+}
+
+/// Système d'animation des matériaux
+///
+/// À la désactivation, les triangles extérieurs reprennent les couleurs du
+/// thème actif et les autres matériaux leur état de l'instant 0.
+#[allow(clippy::too_many_arguments)]
+pub fn material_animation_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    spec: Res<LogoSpec>,
+    palette: Res<Palette>,
+    simulation: Res<CvdSimulation>,
+    mut animation: ResMut<MaterialAnimation>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(&LogoPart, &MeshMaterial3d<StandardMaterial>, Option<&HoverHighlight>)>,
+) {
+    let toggled = keyboard.just_pressed(KeyCode::KeyU);
+    if toggled {
+        animation.enabled = !animation.enabled;
+        animation.time = 0.0;
+    }
+    if !animation.enabled && !toggled {
+        return;
+    }
+    if animation.enabled {
+        animation.time += time.delta_secs();
+    }
+
+    let logo_spec: &LogoSpec = &spec;
+    let spec = &spec.material_animation;
+    let t = animation.time;
+    let hue_offset = t * spec.hue_cycle_speed;
+    let pulse = if animation.enabled { pulse_intensity(t, spec.r_pulse_frequency) } else { 0.0 };
+    let alpha = if animation.enabled { circle_alpha(t, spec) } else { spec.circle_alpha_max };
+
+    for (part, material_handle, highlight) in query.iter() {
+        // La partie survolée porte une copie : l'original est animé aussi
+        for handle in std::iter::once(&material_handle.0).chain(highlight.map(|h| &h.original)) {
+            let highlighted_copy = highlight.is_some() && *handle == material_handle.0;
+            let Some(material) = materials.get_mut(handle) else {
+                continue;
+            };
+            match part {
+                LogoPart::ExteriorTriangle(i) if animation.enabled => {
+                    material.base_color = rainbow_color(*i, hue_offset, spec);
+                }
+                LogoPart::ExteriorTriangle(_) => {
+                    material.base_color = part_color(part, &palette, &simulation, logo_spec);
+                }
+                LogoPart::RPart(_) => {
+                    let emissive = spec.r_emissive * pulse;
+                    material.emissive = if highlighted_copy { highlighted_emissive(emissive) } else { emissive };
+                }
+                LogoPart::SmallCircle(_) => {
+                    material.base_color.set_alpha(alpha);
+                }
+                _ => {}
+            }
+        }
+    }
+}
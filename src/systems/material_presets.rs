@@ -0,0 +1,73 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/material_presets.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'application des préréglages de matériaux
+//!
+//! Chaque catégorie de partie prend le préréglage nommé dans
+//! `LogoSpec::part_materials` (ou son matériau d'origine). Les matériaux
+//! sont modifiés sur place à chaque changement de la spécification, puis
+//! reteintés par la palette.
+//!
+//! - Touche V : Appliquer / retirer les préréglages de
+//!   `config::PART_MATERIALS_FILE`
+
+use bevy::prelude::*;
+use crate::{config, materials};
+use crate::palette::Palette;
+use crate::spec::{LogoSpec, PartMaterials};
+use crate::systems::picking::HoverHighlight;
+use crate::systems::setup::LogoPart;
+
+/// Bascule les préréglages et les applique quand la spécification change
+pub fn material_preset_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut spec: ResMut<LogoSpec>,
+    asset_server: Res<AssetServer>,
+    mut palette: ResMut<Palette>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(&LogoPart, &MeshMaterial3d<StandardMaterial>, Option<&HoverHighlight>)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyV) {
+        if spec.part_materials.is_empty() {
+            match PartMaterials::load(config::PART_MATERIALS_FILE, &spec.presets) {
+                Ok(choices) => {
+                    println!("🪙 Préréglages de matériaux appliqués ({})", config::PART_MATERIALS_FILE);
+                    spec.part_materials = choices;
+                }
+                Err(e) => println!("⚠ Préréglages illisibles ({}) : {}", config::PART_MATERIALS_FILE, e),
+            }
+        } else {
+            println!("🪙 Matériaux d'origine");
+            spec.part_materials = PartMaterials::default();
+        }
+    }
+    if !spec.is_changed() {
+        return;
+    }
+
+    let choices = &spec.part_materials;
+    for choice in [&choices.ring, &choices.exterior, &choices.interior, &choices.small_circle, &choices.r].into_iter().flatten() {
+        let name = &choice.preset;
+        if !spec.presets.iter().any(|preset| &preset.name == name) {
+            println!("⚠ Préréglage de matériau inconnu : {}", name);
+        }
+    }
+
+    for (part, material_handle, highlight) in query.iter() {
+        let preset = spec.preset_for(part);
+        for handle in std::iter::once(&material_handle.0).chain(highlight.map(|h| &h.original)) {
+            let Some(material) = materials.get_mut(handle) else { continue };
+            // L'émissif appartient aux effets (surbrillance, pulsation)
+            let emissive = material.emissive;
+            *material = materials::get_part_material(part);
+            if let Some(preset) = &preset {
+                preset.apply(material, &asset_server);
+            }
+            material.emissive = emissive;
+        }
+    }
+
+    // Les couleurs sont ensuite reprises par la palette
+    palette.set_changed();
+}
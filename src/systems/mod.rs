@@ -5,3 +5,23 @@
 
 pub mod setup;
 pub mod camera;  // NOUVEAU MODULE
+pub mod framing;  // NOUVEAU MODULE
+pub mod camera_path;  // NOUVEAU MODULE
+pub mod fly_camera;  // NOUVEAU MODULE
+pub mod viewports;  // NOUVEAU MODULE
+pub mod picking;  // NOUVEAU MODULE
+pub mod part_motion;  // NOUVEAU MODULE
+pub mod explode;  // NOUVEAU MODULE
+pub mod timeline;  // NOUVEAU MODULE
+pub mod intro;  // NOUVEAU MODULE
+pub mod material_animation;  // NOUVEAU MODULE
+pub mod morph;  // NOUVEAU MODULE
+pub mod audio_reactive;  // NOUVEAU MODULE
+pub mod spring;  // NOUVEAU MODULE
+pub mod shatter;  // NOUVEAU MODULE
+pub mod particles;  // NOUVEAU MODULE
+pub mod palette;  // NOUVEAU MODULE
+pub mod material_presets;  // NOUVEAU MODULE
+pub mod bake;  // NOUVEAU MODULE
+pub mod logo_wall;  // NOUVEAU MODULE
+pub mod lod;  // NOUVEAU MODULE
@@ -0,0 +1,127 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/morph.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de morphing entre deux glyphes
+//!
+//! Les contours du R et ceux du glyphe cible sont appariés partie par partie
+//! (une partie sans correspondant se résorbe en son centre), rééchantillonnés
+//! au même nombre de points, puis interpolés ; le mesh extrudé est
+//! reconstruit à chaque image.
+//!
+//! - Touche M : Morphing aller / retour
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::geometry::RPartDefinition;
+use crate::timeline::Easing;
+use crate::systems::intro::IntroState;
+use crate::systems::setup::LogoPart;
+
+/// Ressource contenant l'état du morphing
+#[derive(Resource, Default)]
+pub struct MorphState {
+    /// Avancement courant (0 : glyphe source, 1 : glyphe cible)
+    pub factor: f32,
+    /// Avancement visé
+    pub target: f32,
+    /// Contours appariés (source, cible) par nom de partie du R
+    pairs: Vec<(&'static str, Vec<Vec2>, Vec<Vec2>)>,
+}
+
+impl MorphState {
+    /// Contour courant d'une partie du R, ou `None` si le morphing n'a
+    /// jamais été lancé (le contour d'origine s'applique)
+    pub fn outline(&self, name: &str) -> Option<Vec<Vec2>> {
+        let (_, a, b) = self.pairs.iter().find(|(n, _, _)| *n == name)?;
+        Some(geometry::interpolate_outlines(a, b, Easing::EaseInOut.apply(self.factor)))
+    }
+}
+
+/// Apparie les parties de deux glyphes par rang
+pub fn match_glyphs(
+    source: &[RPartDefinition],
+    target: &[RPartDefinition],
+    samples: usize,
+) -> Vec<(&'static str, Vec<Vec2>, Vec<Vec2>)> {
+    source
+        .iter()
+        .enumerate()
+        .map(|(i, part)| {
+            let collapsed;
+            let target_points = match target.get(i) {
+                Some(target_part) => target_part.points.as_slice(),
+                None => {
+                    let center = part.points.iter().copied().sum::<Vec2>() / part.points.len() as f32;
+                    collapsed = [center];
+                    &collapsed[..]
+                }
+            };
+            let (a, b) = geometry::match_outlines(&part.points, target_points, samples);
+            (part.name, a, b)
+        })
+        .collect()
+}
+
+/// Système de morphing du R
+///
+/// Bloqué pendant l'apparition, qui reconstruit elle-même les meshes du R
+/// (à partir du contour courant du morphing).
+pub fn morph_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    intro: Res<IntroState>,
+    mut state: ResMut<MorphState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    query: Query<(&LogoPart, &Mesh3d)>,
+) {
+    if intro.playing {
+        return;
+    }
+    if keyboard.just_pressed(KeyCode::KeyM) {
+        state.target = if state.target > 0.5 { 0.0 } else { 1.0 };
+        if state.pairs.is_empty() {
+            state.pairs = match_glyphs(
+                &geometry::get_all_r_parts(),
+                &geometry::get_all_b_parts(),
+                config::MORPH_SAMPLES,
+            );
+        }
+    }
+
+    if state.factor == state.target {
+        return;
+    }
+    let step = time.delta_secs() / config::MORPH_DURATION;
+    let delta = state.target - state.factor;
+    state.factor += delta.clamp(-step, step);
+
+    for (part, mesh_handle) in query.iter() {
+        let LogoPart::RPart(name) = part else {
+            continue;
+        };
+        let Some(outline) = state.outline(name) else {
+            continue;
+        };
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            *mesh = geometry::create_3d_polygon_mesh(&outline, config::DEPTH);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn outline_follows_the_morph_factor() {
+        let mut state = MorphState::default();
+        assert!(state.outline("Arrondi du R").is_none());
+
+        state.pairs = match_glyphs(&geometry::get_all_r_parts(), &geometry::get_all_b_parts(), config::MORPH_SAMPLES);
+        let (_, a, b) = state.pairs.iter().find(|(n, _, _)| *n == "Arrondi du R").unwrap().clone();
+        assert_eq!(state.outline("Arrondi du R").unwrap(), a);
+        state.factor = 1.0;
+        assert_eq!(state.outline("Arrondi du R").unwrap(), b);
+    }
+}
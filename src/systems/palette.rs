@@ -0,0 +1,118 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/palette.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'application des thèmes de couleurs
+//!
+//! Les matériaux existants sont reteintés sur place, sans recréer les meshes :
+//! - Touche N : Thème suivant
+//! - Touche H : Simuler un daltonisme (protanopie, deutéranopie,
+//!   tritanopie) et lister les parties voisines qui se confondent
+//!
+//! Les thèmes de `config::THEME_FILE` s'ajoutent aux thèmes intégrés.
+
+use bevy::prelude::*;
+use std::io;
+use crate::config;
+use crate::cvd::{self, ColorDeficiency};
+use crate::palette::{self, Palette};
+use crate::spec::LogoSpec;
+use crate::systems::picking::HoverHighlight;
+use crate::systems::setup::LogoPart;
+
+/// Ressource contenant la déficience de vision simulée
+#[derive(Resource, Default)]
+pub struct CvdSimulation {
+    pub deficiency: Option<ColorDeficiency>,
+}
+
+/// Déficience suivante dans le cycle (aucune → protanopie → … → aucune)
+fn next_deficiency(current: Option<ColorDeficiency>) -> Option<ColorDeficiency> {
+    match current {
+        None => Some(ColorDeficiency::Protanopia),
+        Some(ColorDeficiency::Protanopia) => Some(ColorDeficiency::Deuteranopia),
+        Some(ColorDeficiency::Deuteranopia) => Some(ColorDeficiency::Tritanopia),
+        Some(ColorDeficiency::Tritanopia) => None,
+    }
+}
+
+/// Affiche les parties voisines confondues pour le thème actif
+fn print_accessibility_report(palette: &Palette, deficiency: ColorDeficiency) {
+    let theme = palette.theme();
+    let pairs = cvd::confused_pairs(theme, deficiency);
+    if pairs.is_empty() {
+        println!("👁 Thème « {} », {} : aucune partie voisine confondue", theme.name, deficiency);
+        return;
+    }
+    println!("👁 Thème « {} », {} : {} couple(s) confondu(s)", theme.name, deficiency, pairs.len());
+    for pair in &pairs {
+        println!(
+            "   • {} / {} : ΔE {:.1} → {:.1}",
+            pair.a, pair.b, pair.normal_delta_e, pair.simulated_delta_e
+        );
+    }
+}
+
+/// Couleur de base d'une partie : préréglage ou thème actif, vue à travers
+/// la déficience simulée
+pub fn part_color(part: &LogoPart, palette: &Palette, simulation: &CvdSimulation, spec: &LogoSpec) -> Color {
+    // Les préréglages métalliques (or, cuivre...) gardent leur propre couleur
+    let color = spec
+        .preset_for(part)
+        .and_then(|preset| preset.base_color)
+        .unwrap_or_else(|| palette.theme().part_color(part));
+    simulation.deficiency.map_or(color, |d| d.simulate(color))
+}
+
+/// Charge le fichier de thèmes s'il existe
+pub fn load_theme_file_system(mut palette: ResMut<Palette>) {
+    match palette::load_themes(config::THEME_FILE) {
+        Ok(themes) => {
+            println!("🎨 {} thème(s) chargé(s) depuis {}", themes.len(), config::THEME_FILE);
+            palette.merge(themes);
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => println!("⚠ Thèmes illisibles ({}) : {}", config::THEME_FILE, e),
+    }
+}
+
+/// Système de changement de thème et de simulation du daltonisme
+pub fn palette_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut palette: ResMut<Palette>,
+    mut simulation: ResMut<CvdSimulation>,
+    spec: Res<LogoSpec>,
+    mut clear_color: ResMut<ClearColor>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    query: Query<(&LogoPart, &MeshMaterial3d<StandardMaterial>, Option<&HoverHighlight>)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyN) {
+        palette.next();
+        println!("🎨 Thème : {}", palette.theme().name);
+    }
+    if keyboard.just_pressed(KeyCode::KeyH) {
+        simulation.deficiency = next_deficiency(simulation.deficiency);
+        if simulation.deficiency.is_none() {
+            println!("👁 Vision normale");
+        }
+    }
+    if !palette.is_changed() && !simulation.is_changed() {
+        return;
+    }
+    if let Some(deficiency) = simulation.deficiency {
+        print_accessibility_report(&palette, deficiency);
+    }
+
+    // Couleurs du thème, vues à travers la déficience simulée
+    let background = palette.theme().background;
+    clear_color.0 = simulation.deficiency.map_or(background, |d| d.simulate(background));
+    for (part, material_handle, highlight) in query.iter() {
+        let color = part_color(part, &palette, &simulation, &spec);
+        // La partie survolée porte une copie : l'original est reteinté aussi
+        for handle in std::iter::once(&material_handle.0).chain(highlight.map(|h| &h.original)) {
+            if let Some(material) = materials.get_mut(handle) {
+                material.base_color = color;
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/part_motion.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module commun aux animations des parties du logo
+//!
+//! Chaque image, le `Transform` de chaque partie est remis à sa position de
+//! repos, puis chaque effet (vue éclatée, etc.) y ajoute son décalage. Les
+//! effets se combinent ainsi sans se contredire.
+
+use bevy::prelude::*;
+use crate::geometry;
+use crate::systems::setup::LogoPart;
+
+/// Transform de repos d'une partie (tel que créé par `setup_system`)
+#[derive(Component, Clone, Copy)]
+pub struct RestTransform(pub Transform);
+
+/// Pivot d'une partie : centre de gravité de son mesh (espace local)
+///
+/// Les rotations et mises à l'échelle des effets se font autour de ce point
+/// pour que chaque pièce s'anime sur place.
+#[derive(Component, Clone, Copy)]
+pub struct PartPivot(pub Vec3);
+
+/// Étapes d'animation des parties
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PartMotionSet {
+    /// Remise des parties à leur position de repos
+    Reset,
+    /// Application des effets
+    Animate,
+    /// Effets qui remplacent entièrement la pose (bris, etc.)
+    Override,
+}
+
+/// Mémorise le transform de repos et le pivot des nouvelles parties
+#[allow(clippy::type_complexity)]
+pub fn init_rest_transform_system(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    query: Query<(Entity, &Transform, &Mesh3d), (With<LogoPart>, Without<RestTransform>)>,
+) {
+    for (entity, transform, mesh_handle) in query.iter() {
+        let pivot = meshes
+            .get(&mesh_handle.0)
+            .map(|mesh| geometry::calculate_centroid(&geometry::mesh_positions(mesh)))
+            .unwrap_or(Vec3::ZERO);
+        commands.entity(entity).insert((RestTransform(*transform), PartPivot(pivot)));
+    }
+}
+
+/// Remet chaque partie à sa position de repos avant les effets
+pub fn reset_part_transforms_system(mut query: Query<(&RestTransform, &mut Transform)>) {
+    for (rest, mut transform) in query.iter_mut() {
+        *transform = rest.0;
+    }
+}
+
+/// Applique une rotation et une échelle locales autour du pivot de la partie
+pub fn apply_about_pivot(transform: &mut Transform, pivot: Vec3, rotation: Quat, scale: Vec3) {
+    let local = Transform {
+        translation: pivot - rotation * (scale * pivot),
+        rotation,
+        scale,
+    };
+    *transform = transform.mul_transform(local);
+}
@@ -0,0 +1,391 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/particles.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de particules (calcul CPU)
+//!
+//! Des émetteurs attachés aux parties du logo lancent des particules :
+//! - Étincelles depuis la pointe des triangles extérieurs
+//! - Traînée lumineuse derrière l'arrondi du R
+//! - Touche Y : Activer / désactiver les particules
+//!
+//! Les particules sont créées une fois pour toutes au démarrage (pool) puis
+//! réutilisées : aucune allocation n'a lieu pendant l'animation. Elles
+//! partagent quelques matériaux (une couleur par tranche d'âge) : aucun
+//! matériau n'est modifié pendant l'animation et le rendu reste groupé.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::physics::DeterministicRng;
+use crate::systems::setup::LogoPart;
+
+/// Valeur interpolable le long d'une courbe de vie
+pub trait LifeValue: Copy {
+    fn lerp_to(self, other: Self, t: f32) -> Self;
+}
+
+impl LifeValue for f32 {
+    fn lerp_to(self, other: Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl LifeValue for LinearRgba {
+    fn lerp_to(self, other: Self, t: f32) -> Self {
+        LinearRgba::from_vec4(self.to_vec4().lerp(other.to_vec4(), t))
+    }
+}
+
+/// Courbe en fonction de l'âge relatif (0 : naissance, 1 : mort)
+///
+/// Les clés sont statiques : copier une courbe ne coûte rien.
+#[derive(Clone, Copy, Debug)]
+pub struct LifeCurve<T: 'static> {
+    /// Couples (âge relatif, valeur), triés par âge
+    pub keys: &'static [(f32, T)],
+}
+
+impl<T: LifeValue> LifeCurve<T> {
+    /// Valeur à l'âge relatif `t` (interpolation linéaire entre les clés)
+    pub fn sample(&self, t: f32) -> T {
+        let (first, last) = (self.keys[0], self.keys[self.keys.len() - 1]);
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+        let next = self.keys.iter().position(|(time, _)| *time > t).unwrap_or(self.keys.len() - 1);
+        let (t0, v0) = self.keys[next - 1];
+        let (t1, v1) = self.keys[next];
+        v0.lerp_to(v1, (t - t0) / (t1 - t0).max(f32::EPSILON))
+    }
+}
+
+/// Couleur des étincelles : blanc chaud → orange → éteint
+const SPARK_COLOR: LifeCurve<LinearRgba> = LifeCurve {
+    keys: &[
+        (0.0, LinearRgba::new(4.0, 3.5, 2.0, 1.0)),
+        (0.3, LinearRgba::new(3.0, 1.2, 0.2, 1.0)),
+        (1.0, LinearRgba::new(0.4, 0.05, 0.0, 0.0)),
+    ],
+};
+
+/// Taille des étincelles : se réduisent jusqu'à disparaître
+const SPARK_SIZE: LifeCurve<f32> = LifeCurve { keys: &[(0.0, 2.5), (1.0, 0.3)] };
+
+/// Couleur de la traînée du R
+const TRAIL_COLOR: LifeCurve<LinearRgba> = LifeCurve {
+    keys: &[
+        (0.0, LinearRgba::new(2.0, 0.8, 0.1, 0.8)),
+        (1.0, LinearRgba::new(0.6, 0.1, 0.0, 0.0)),
+    ],
+};
+
+/// Taille de la traînée : gonfle puis s'estompe
+const TRAIL_SIZE: LifeCurve<f32> = LifeCurve { keys: &[(0.0, 6.0), (0.4, 9.0), (1.0, 2.0)] };
+
+/// Courbes de couleur des particules, chacune déclinée en matériaux partagés
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParticleColors {
+    Spark,
+    Trail,
+}
+
+impl ParticleColors {
+    pub const ALL: [ParticleColors; 2] = [ParticleColors::Spark, ParticleColors::Trail];
+
+    pub fn curve(self) -> LifeCurve<LinearRgba> {
+        match self {
+            ParticleColors::Spark => SPARK_COLOR,
+            ParticleColors::Trail => TRAIL_COLOR,
+        }
+    }
+}
+
+/// Tranche d'âge (matériau partagé) d'une particule d'âge relatif `t`
+pub fn color_step(t: f32) -> usize {
+    ((t.clamp(0.0, 1.0) * config::PARTICLE_COLOR_STEPS as f32) as usize).min(config::PARTICLE_COLOR_STEPS - 1)
+}
+
+/// Émetteur attaché à une partie du logo
+#[derive(Component, Clone, Copy)]
+pub struct ParticleEmitter {
+    /// Particules émises par seconde
+    pub rate: f32,
+    /// Point d'émission (espace local de la partie)
+    pub offset: Vec3,
+    /// Vitesse initiale (espace local de la partie)
+    pub velocity: Vec3,
+    /// Écart aléatoire ajouté à la vitesse (unités par seconde)
+    pub spread: f32,
+    /// Durée de vie (secondes)
+    pub lifetime: f32,
+    /// Freinage (fraction de vitesse perdue par seconde)
+    pub drag: f32,
+    /// Accélération verticale
+    pub gravity: f32,
+    pub color: ParticleColors,
+    pub size: LifeCurve<f32>,
+    /// Fraction de particule restant à émettre
+    pub accumulator: f32,
+    pub rng: DeterministicRng,
+}
+
+/// Particule du pool
+#[derive(Component, Clone, Copy)]
+pub struct Particle {
+    pub alive: bool,
+    pub age: f32,
+    pub lifetime: f32,
+    pub velocity: Vec3,
+    pub drag: f32,
+    pub gravity: f32,
+    pub color: ParticleColors,
+    /// Tranche d'âge dont le matériau est affiché
+    pub color_step: usize,
+    pub size: LifeCurve<f32>,
+}
+
+/// Ressource contenant le pool de particules
+#[derive(Resource)]
+pub struct ParticlePool {
+    pub enabled: bool,
+    pub entities: Vec<Entity>,
+    /// Prochaine particule à réutiliser (la plus ancienne)
+    pub next: usize,
+    /// Matériaux partagés, par courbe de couleur puis par tranche d'âge
+    pub materials: Vec<Vec<Handle<StandardMaterial>>>,
+}
+
+impl ParticlePool {
+    /// Matériau d'une courbe de couleur pour une tranche d'âge
+    pub fn material(&self, color: ParticleColors, step: usize) -> Handle<StandardMaterial> {
+        self.materials[color as usize][step].clone()
+    }
+}
+
+/// Crée le pool de particules, cachées jusqu'à leur émission
+pub fn setup_particle_pool_system(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Sphere::new(1.0).mesh().ico(1).unwrap());
+
+    // Chaque tranche d'âge prend la couleur du milieu de la tranche
+    let steps = config::PARTICLE_COLOR_STEPS;
+    let shared: Vec<Vec<Handle<StandardMaterial>>> = ParticleColors::ALL
+        .iter()
+        .map(|colors| {
+            (0..steps)
+                .map(|step| {
+                    materials.add(StandardMaterial {
+                        base_color: colors.curve().sample((step as f32 + 0.5) / steps as f32).into(),
+                        unlit: true,
+                        alpha_mode: AlphaMode::Add,
+                        ..default()
+                    })
+                })
+                .collect()
+        })
+        .collect();
+
+    let entities = (0..config::PARTICLE_POOL_SIZE)
+        .map(|_| {
+            commands
+                .spawn((
+                    Mesh3d(mesh.clone()),
+                    MeshMaterial3d(shared[ParticleColors::Spark as usize][0].clone()),
+                    Transform::default(),
+                    Visibility::Hidden,
+                    Particle {
+                        alive: false,
+                        age: 0.0,
+                        lifetime: 1.0,
+                        velocity: Vec3::ZERO,
+                        drag: 0.0,
+                        gravity: 0.0,
+                        color: ParticleColors::Spark,
+                        color_step: 0,
+                        size: SPARK_SIZE,
+                    },
+                ))
+                .id()
+        })
+        .collect();
+    commands.insert_resource(ParticlePool { enabled: true, entities, next: 0, materials: shared });
+}
+
+/// Point de la pointe d'un triangle : le sommet le plus éloigné du centre
+fn triangle_tip(positions: &[Vec3]) -> Vec3 {
+    positions
+        .iter()
+        .copied()
+        .max_by(|a, b| a.truncate().length_squared().total_cmp(&b.truncate().length_squared()))
+        .unwrap_or(Vec3::ZERO)
+}
+
+/// Attache les émetteurs aux nouvelles parties
+pub fn init_particle_emitters_system(
+    mut commands: Commands,
+    meshes: Res<Assets<Mesh>>,
+    query: Query<(Entity, &LogoPart, &Mesh3d), Added<LogoPart>>,
+) {
+    for (entity, part, mesh_handle) in query.iter() {
+        let Some(mesh) = meshes.get(&mesh_handle.0) else { continue };
+        let positions = geometry::mesh_positions(mesh);
+        let emitter = match part {
+            LogoPart::ExteriorTriangle(i) => {
+                let tip = triangle_tip(&positions);
+                ParticleEmitter {
+                    rate: 4.0,
+                    offset: tip,
+                    velocity: (tip - geometry::calculate_centroid(&positions)).normalize_or_zero() * 120.0,
+                    spread: 40.0,
+                    lifetime: 0.8,
+                    drag: 1.5,
+                    gravity: -150.0,
+                    color: ParticleColors::Spark,
+                    size: SPARK_SIZE,
+                    accumulator: 0.0,
+                    rng: DeterministicRng::new(*i as u32 + 1),
+                }
+            }
+            LogoPart::RPart("Arrondi du R") => ParticleEmitter {
+                rate: 60.0,
+                offset: geometry::calculate_centroid(&positions),
+                velocity: Vec3::ZERO,
+                spread: 8.0,
+                lifetime: 0.6,
+                drag: 2.0,
+                gravity: 0.0,
+                color: ParticleColors::Trail,
+                size: TRAIL_SIZE,
+                accumulator: 0.0,
+                rng: DeterministicRng::new(1000),
+            },
+            _ => continue,
+        };
+        commands.entity(entity).insert(emitter);
+    }
+}
+
+/// Émet les particules en réutilisant les plus anciennes du pool
+pub fn particle_emit_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut pool: ResMut<ParticlePool>,
+    mut emitters: Query<(&mut ParticleEmitter, &GlobalTransform, &InheritedVisibility)>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyY) {
+        pool.enabled = !pool.enabled;
+        println!("✨ Particules : {}", if pool.enabled { "activées" } else { "désactivées" });
+    }
+    if !pool.enabled || pool.entities.is_empty() {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    for (mut emitter, global, visibility) in emitters.iter_mut() {
+        // Pas d'émission depuis une partie encore cachée (apparition)
+        if !visibility.get() {
+            emitter.accumulator = 0.0;
+            continue;
+        }
+        emitter.accumulator += emitter.rate * dt;
+        while emitter.accumulator >= 1.0 {
+            emitter.accumulator -= 1.0;
+
+            let jitter = Vec3::new(
+                emitter.rng.range(-1.0, 1.0),
+                emitter.rng.range(-1.0, 1.0),
+                emitter.rng.range(-1.0, 1.0),
+            ) * emitter.spread;
+            let entity = pool.entities[pool.next];
+            pool.next = (pool.next + 1) % pool.entities.len();
+
+            let Ok((mut particle, mut transform, mut material)) = particles.get_mut(entity) else { continue };
+            *particle = Particle {
+                alive: true,
+                age: 0.0,
+                lifetime: emitter.lifetime,
+                velocity: global.affine().transform_vector3(emitter.velocity) + jitter,
+                drag: emitter.drag,
+                gravity: emitter.gravity,
+                color: emitter.color,
+                color_step: 0,
+                size: emitter.size,
+            };
+            material.0 = pool.material(emitter.color, 0);
+            transform.translation = global.transform_point(emitter.offset);
+            transform.scale = Vec3::splat(emitter.size.sample(0.0));
+        }
+    }
+}
+
+/// Fait vieillir et avancer les particules vivantes
+///
+/// La couleur change par tranche d'âge, en passant d'un matériau partagé au
+/// suivant ; la taille suit sa courbe en continu.
+pub fn particle_update_system(
+    time: Res<Time>,
+    pool: Res<ParticlePool>,
+    mut particles: Query<(&mut Particle, &mut Transform, &mut Visibility, &mut MeshMaterial3d<StandardMaterial>)>,
+) {
+    let dt = time.delta_secs();
+    for (mut particle, mut transform, mut visibility, mut material) in particles.iter_mut() {
+        if !particle.alive {
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            particle.alive = false;
+            visibility.set_if_neq(Visibility::Hidden);
+            continue;
+        }
+
+        // === MOUVEMENT ===
+        let drag = (1.0 - particle.drag * dt).max(0.0);
+        particle.velocity = particle.velocity * drag + Vec3::Y * particle.gravity * dt;
+        transform.translation += particle.velocity * dt;
+
+        // === COURBES DE VIE ===
+        let t = particle.age / particle.lifetime;
+        transform.scale = Vec3::splat(particle.size.sample(t));
+        let step = color_step(t);
+        if step != particle.color_step {
+            particle.color_step = step;
+            material.0 = pool.material(particle.color, step);
+        }
+        visibility.set_if_neq(Visibility::Visible);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn life_curve_interpolates_between_keys() {
+        const CURVE: LifeCurve<f32> = LifeCurve { keys: &[(0.0, 2.0), (0.5, 4.0), (1.0, 0.0)] };
+        assert_eq!(CURVE.sample(-1.0), 2.0);
+        assert_eq!(CURVE.sample(0.25), 3.0);
+        assert_eq!(CURVE.sample(0.5), 4.0);
+        assert_eq!(CURVE.sample(0.75), 2.0);
+        assert_eq!(CURVE.sample(2.0), 0.0);
+    }
+
+    #[test]
+    fn color_steps_cover_the_whole_life() {
+        let last = config::PARTICLE_COLOR_STEPS - 1;
+        assert_eq!(color_step(-0.5), 0);
+        assert_eq!(color_step(0.0), 0);
+        assert_eq!(color_step(0.999), last);
+        assert_eq!(color_step(1.0), last);
+        let steps: Vec<usize> = (0..=100).map(|i| color_step(i as f32 / 100.0)).collect();
+        assert!(steps.windows(2).all(|w| w[1] == w[0] || w[1] == w[0] + 1));
+    }
+}
@@ -0,0 +1,235 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/picking.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de sélection à la souris
+//!
+//! Un rayon est lancé depuis le curseur à travers la caméra de la vue
+//! survolée, puis testé contre les meshes CPU de chaque partie :
+//! - Survol : surbrillance émissive de la partie
+//! - Clic gauche : affichage de l'identité de la partie et sélection
+
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::picking;
+use crate::systems::bake::BakedLogo;
+use crate::systems::framing::SelectedPart;
+use crate::systems::setup::LogoPart;
+
+/// Couleur émissive appliquée à la partie survolée
+pub const HIGHLIGHT_EMISSIVE: LinearRgba = LinearRgba::rgb(0.6, 0.6, 0.3);
+
+/// Partie touchée par le rayon du curseur
+#[derive(Clone, Copy, Debug)]
+pub struct PickHit {
+    pub entity: Entity,
+    pub part: LogoPart,
+    /// Point touché (espace monde)
+    pub point: Vec3,
+    /// Normale de la face touchée (espace monde)
+    pub normal: Vec3,
+}
+
+/// Ressource contenant la partie actuellement survolée
+#[derive(Resource, Default)]
+pub struct HoveredPart(pub Option<PickHit>);
+
+/// Composant posé sur la partie surlignée : matériau d'origine à restaurer
+#[derive(Component)]
+pub struct HoverHighlight {
+    pub original: Handle<StandardMaterial>,
+}
+
+/// Émission de la copie surlignée quand un effet anime celle de la partie
+///
+/// La surbrillance reste visible : chaque canal vaut au moins celui de
+/// `HIGHLIGHT_EMISSIVE`.
+pub fn highlighted_emissive(emissive: LinearRgba) -> LinearRgba {
+    LinearRgba::rgb(
+        emissive.red.max(HIGHLIGHT_EMISSIVE.red),
+        emissive.green.max(HIGHLIGHT_EMISSIVE.green),
+        emissive.blue.max(HIGHLIGHT_EMISSIVE.blue),
+    )
+}
+
+/// Applique une émission animée à une partie, survolée ou non
+///
+/// Le matériau d'origine reçoit `emissive` ; la copie surlignée garde au
+/// moins la surbrillance.
+pub fn set_part_emissive(
+    materials: &mut Assets<StandardMaterial>,
+    displayed: &Handle<StandardMaterial>,
+    highlight: Option<&HoverHighlight>,
+    emissive: LinearRgba,
+) {
+    match highlight {
+        Some(highlight) => {
+            if let Some(material) = materials.get_mut(&highlight.original) {
+                material.emissive = emissive;
+            }
+            if let Some(material) = materials.get_mut(displayed) {
+                material.emissive = highlighted_emissive(emissive);
+            }
+        }
+        None => {
+            if let Some(material) = materials.get_mut(displayed) {
+                material.emissive = emissive;
+            }
+        }
+    }
+}
+
+/// Lance un rayon (espace monde) contre une partie du logo
+///
+/// Renvoie la distance, le point, la normale et l'indice du triangle touché.
+fn pick_part(
+    ray: Ray3d,
+    mesh: &Mesh,
+    transform: &GlobalTransform,
+) -> Option<(f32, Vec3, Vec3, usize)> {
+    // Le rayon est ramené dans l'espace local du mesh
+    let world_from_local = transform.affine();
+    let local_from_world = world_from_local.inverse();
+    let origin = local_from_world.transform_point3(ray.origin);
+    let direction = local_from_world.transform_vector3(*ray.direction);
+
+    let hit = picking::ray_mesh_intersection(origin, direction, mesh)?;
+
+    let point = world_from_local.transform_point3(hit.point);
+    let normal = (local_from_world.matrix3.transpose() * hit.normal).normalize_or_zero();
+    Some((ray.origin.distance(point), point, normal, hit.triangle))
+}
+
+/// Système de sélection : survol et clic
+#[allow(clippy::too_many_arguments)]
+pub fn picking_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    meshes: Res<Assets<Mesh>>,
+    mut hovered: ResMut<HoveredPart>,
+    mut selected: ResMut<SelectedPart>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    parts: Query<(Entity, &LogoPart, &Mesh3d, &GlobalTransform, &InheritedVisibility)>,
+    baked: Query<(&BakedLogo, &Mesh3d, &GlobalTransform, &InheritedVisibility)>,
+) {
+    let cursor = windows.single().ok().and_then(|window| window.cursor_position());
+
+    // Caméra dont la vue contient le curseur
+    let ray = cursor.and_then(|cursor| {
+        cameras
+            .iter()
+            .filter(|(camera, _)| camera.is_active)
+            .filter(|(camera, _)| camera.logical_viewport_rect().is_some_and(|r| r.contains(cursor)))
+            .max_by_key(|(camera, _)| camera.order)
+            .and_then(|(camera, transform)| camera.viewport_to_world(transform, cursor).ok())
+    });
+
+    // === RECHERCHE DE LA PARTIE LA PLUS PROCHE ===
+    let mut closest: Option<(f32, PickHit)> = None;
+    if let Some(ray) = ray {
+        for (entity, part, mesh_handle, transform, visibility) in parts.iter() {
+            // Les parties cachées (apparition, logo fusionné) ne sont pas sélectionnables
+            if !visibility.get() {
+                continue;
+            }
+            let Some(mesh) = meshes.get(&mesh_handle.0) else {
+                continue;
+            };
+            let Some((distance, point, normal, _)) = pick_part(ray, mesh, transform) else {
+                continue;
+            };
+            if closest.is_none_or(|(best, _)| distance < best) {
+                closest = Some((distance, PickHit { entity, part: *part, point, normal }));
+            }
+        }
+
+        // Mesh fusionné : le triangle touché désigne la partie d'origine
+        for (logo, mesh_handle, transform, visibility) in baked.iter() {
+            if !visibility.get() {
+                continue;
+            }
+            let Some(mesh) = meshes.get(&mesh_handle.0) else {
+                continue;
+            };
+            let Some((distance, point, normal, triangle)) = pick_part(ray, mesh, transform) else {
+                continue;
+            };
+            let Some(part) = logo.part_for_triangle(triangle) else {
+                continue;
+            };
+            if closest.is_none_or(|(best, _)| distance < best) {
+                closest = Some((distance, PickHit { entity: part.entity, part: part.part, point, normal }));
+            }
+        }
+    }
+    hovered.0 = closest.map(|(_, hit)| hit);
+
+    // === CLIC : IDENTIFICATION ET SÉLECTION ===
+    if mouse_button.just_pressed(MouseButton::Left)
+        && let Some(hit) = hovered.0
+    {
+        println!(
+            "🎯 {} — point ({:.1}, {:.1}, {:.1}), normale ({:.2}, {:.2}, {:.2})",
+            hit.part, hit.point.x, hit.point.y, hit.point.z, hit.normal.x, hit.normal.y, hit.normal.z
+        );
+        selected.0 = Some(hit.entity);
+    }
+}
+
+/// Système de surbrillance de la partie survolée
+///
+/// La partie reçoit une copie émissive de son matériau, ce qui évite
+/// d'allumer les autres parties partageant le même matériau.
+pub fn hover_highlight_system(
+    mut commands: Commands,
+    hovered: Res<HoveredPart>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut highlighted: Query<(Entity, &HoverHighlight, &mut MeshMaterial3d<StandardMaterial>)>,
+    mut candidates: Query<&mut MeshMaterial3d<StandardMaterial>, (With<LogoPart>, Without<HoverHighlight>)>,
+) {
+    let target = hovered.0.map(|hit| hit.entity);
+
+    // === RESTAURATION DE L'ANCIENNE SURBRILLANCE ===
+    for (entity, highlight, mut material) in highlighted.iter_mut() {
+        if Some(entity) == target {
+            continue;
+        }
+        materials.remove(&material.0);
+        material.0 = highlight.original.clone();
+        commands.entity(entity).remove::<HoverHighlight>();
+    }
+
+    // === NOUVELLE SURBRILLANCE ===
+    if let Some(entity) = target
+        && let Ok(mut material) = candidates.get_mut(entity)
+        && let Some(original) = materials.get(&material.0)
+    {
+        let highlight = StandardMaterial {
+            emissive: HIGHLIGHT_EMISSIVE,
+            ..original.clone()
+        };
+        let original = std::mem::replace(&mut material.0, materials.add(highlight));
+        commands.entity(entity).insert(HoverHighlight { original });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn animated_emissive_keeps_the_highlight() {
+        let mut materials = Assets::<StandardMaterial>::default();
+        let original = materials.add(StandardMaterial::default());
+        let copy = materials.add(StandardMaterial { emissive: HIGHLIGHT_EMISSIVE, ..default() });
+        let highlight = HoverHighlight { original: original.clone() };
+
+        let pulse = LinearRgba::rgb(1.0, 0.2, 0.0);
+        set_part_emissive(&mut materials, &copy, Some(&highlight), pulse);
+        assert_eq!(materials.get(&original).unwrap().emissive, pulse);
+        assert_eq!(materials.get(&copy).unwrap().emissive, LinearRgba::rgb(1.0, 0.6, 0.3));
+
+        set_part_emissive(&mut materials, &original, None, LinearRgba::BLACK);
+        assert_eq!(materials.get(&original).unwrap().emissive, LinearRgba::BLACK);
+    }
+}
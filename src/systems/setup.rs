@@ -4,27 +4,75 @@
 
 use bevy::prelude::*;
 use crate::{config, materials, geometry};
-use crate::systems::camera::{OrbitCamera, RotatingObject};
+use crate::cache::{MaterialCache, MeshCache, MeshKey};
+use crate::systems::camera::{build_projection, orbit_transform, OrbitCamera, RotatingObject};
+use crate::systems::fly_camera::FlyCamera;
+use crate::systems::viewports::{self, ViewportSlot};
+
+/// Composant identifiant une partie du logo
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogoPart {
+    /// Anneau principal
+    Ring,
+    /// Triangle extérieur (indice autour de l'anneau)
+    ExteriorTriangle(usize),
+    /// Grand triangle intérieur
+    InteriorTriangle(usize),
+    /// Petit cercle au centre d'un triangle intérieur
+    SmallCircle(usize),
+    /// Partie du R (nom issu de `get_all_r_parts`)
+    RPart(&'static str),
+}
+
+impl std::fmt::Display for LogoPart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LogoPart::Ring => write!(f, "Anneau principal"),
+            LogoPart::ExteriorTriangle(i) => write!(f, "Triangle extérieur n°{}", i),
+            LogoPart::InteriorTriangle(i) => write!(f, "Triangle intérieur n°{}", i),
+            LogoPart::SmallCircle(i) => write!(f, "Petit cercle n°{}", i),
+            LogoPart::RPart(name) => write!(f, "{}", name),
+        }
+    }
+}
 
 /// Système principal d'initialisation
 pub fn setup_system(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material_cache: ResMut<MaterialCache>,
+    mut mesh_cache: ResMut<MeshCache>,
 ) {
     // === CAMÉRA 3D AVEC CONTRÔLE ORBITAL ===
-    let camera_angle_rad = geometry::degrees_to_radians(config::CAMERA_ANGLE);
+    // La position initiale vient de la même conversion que celle du
+    // système de contrôle : pas de saut à la première image
+    let orbit = OrbitCamera::default();
     commands.spawn((
         Camera3d::default(),
-        Transform::from_xyz(
-            0.0,
-            -config::CAMERA_DISTANCE * camera_angle_rad.sin(),
-            config::CAMERA_DISTANCE * camera_angle_rad.cos(),
-        )
-        .looking_at(Vec3::ZERO, Vec3::Y),
-        OrbitCamera::default(),
+        orbit_transform(&orbit),
+        orbit,
+        FlyCamera::default(),
+        ViewportSlot(0),
     ));
 
+    // === CAMÉRAS SUPPLÉMENTAIRES POUR L'ÉCRAN PARTAGÉ (INACTIVES) ===
+    for slot in 1..viewports::MAX_VIEWPORTS {
+        let orbit = viewports::viewport_preset(slot);
+        commands.spawn((
+            Camera3d::default(),
+            Camera {
+                order: slot as isize,
+                is_active: false,
+                ..default()
+            },
+            build_projection(&orbit),
+            orbit_transform(&orbit),
+            orbit,
+            ViewportSlot(slot),
+        ));
+    }
+
     // === LUMIÈRES ===
     commands.spawn((
         DirectionalLight {
@@ -51,12 +99,10 @@ pub fn setup_system(
     )).id();
 
     // Créer tous les enfants et les stocker dans un Vec
-    let mut children = Vec::new();
-    
-    children.append(&mut create_main_circle(&mut commands, &mut meshes, &mut materials));
-    children.append(&mut create_exterior_triangles(&mut commands, &mut meshes, &mut materials));
-    children.append(&mut create_interior_triangles(&mut commands, &mut meshes, &mut materials));
-    children.append(&mut create_r_logo(&mut commands, &mut meshes, &mut materials));
+    let children: Vec<Entity> = logo_parts()
+        .into_iter()
+        .map(|spec| spawn_part(&mut commands, &mut meshes, &mut materials, &mut material_cache, &mut mesh_cache, spec))
+        .collect();
     
     // Attacher tous les enfants au parent
     commands.entity(parent_id).add_children(&children);
@@ -64,36 +110,59 @@ pub fn setup_system(
     print_creation_summary();
 }
 
-// === FONCTIONS DE CRÉATION (RETOURNENT VEC<Entity>) ===
+// === LISTE DES PARTIES ===
 
-fn create_main_circle(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Vec<Entity> {
-    let mesh = geometry::create_3d_ring_mesh(
-        config::CIRCLE_RADIUS,
-        config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS,
-        config::DEPTH,
-        config::CIRCLE_SEGMENTS,
-    );
-
-    let entity = commands.spawn((
-        Mesh3d(meshes.add(mesh)),
-        MeshMaterial3d(materials.add(materials::get_main_circle_material())),
-        Transform::from_xyz(0.0, 0.0, 0.0),
-    )).id();
-    
-    vec![entity]
+/// Forme d'une partie, dans le repère du logo
+#[derive(Clone, Debug)]
+pub enum PartShape {
+    Ring { outer_radius: f32, inner_radius: f32 },
+    Triangle(Vec2, Vec2, Vec2),
+    Cylinder { radius: f32 },
+    Polygon(Vec<Vec2>),
 }
 
-fn create_exterior_triangles(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Vec<Entity> {
-    let mut entities = Vec::new();
-    
+impl PartShape {
+    /// Construit le mesh de la forme ; les segments fixent la finesse de
+    /// l'anneau et des petits cercles
+    pub fn mesh(&self, ring_segments: usize, circle_segments: usize) -> Mesh {
+        match self {
+            PartShape::Ring { outer_radius, inner_radius } => {
+                geometry::create_3d_ring_mesh(*outer_radius, *inner_radius, config::DEPTH, ring_segments)
+            }
+            PartShape::Triangle(p1, p2, p3) => geometry::create_3d_triangle_mesh(*p1, *p2, *p3, config::DEPTH),
+            PartShape::Cylinder { radius } => geometry::create_3d_cylinder_mesh(*radius, config::DEPTH, circle_segments),
+            PartShape::Polygon(points) => geometry::create_3d_polygon_mesh(points, config::DEPTH),
+        }
+    }
+}
+
+/// Description d'une partie du logo : identité, forme et position
+#[derive(Clone, Debug)]
+pub struct PartSpec {
+    pub part: LogoPart,
+    pub shape: PartShape,
+    pub transform: Transform,
+}
+
+impl PartSpec {
+    /// Matériau d'origine de la partie
+    pub fn material(&self) -> StandardMaterial {
+        materials::get_part_material(&self.part)
+    }
+}
+
+/// Toutes les parties du logo, dans l'ordre de création
+///
+/// Source unique des angles et des positions : la scène principale et le
+/// mur de logos en dérivent tous deux.
+pub fn logo_parts() -> Vec<PartSpec> {
+    let inner_radius = config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS;
+    let mut parts = vec![PartSpec {
+        part: LogoPart::Ring,
+        shape: PartShape::Ring { outer_radius: config::CIRCLE_RADIUS, inner_radius },
+        transform: Transform::IDENTITY,
+    }];
+
     for i in 0..config::EXTERIOR_TRIANGLES_COUNT {
         let base_angle = geometry::degrees_to_radians((i as f32) * 10.0);
         let (p1, p2, p3) = geometry::calculate_exterior_triangle_points(
@@ -101,87 +170,80 @@ fn create_exterior_triangles(
             config::CIRCLE_RADIUS,
             config::SMALL_TRIANGLE_SIDE,
         );
-
-        let mesh = geometry::create_3d_triangle_mesh(p1, p2, p3, config::DEPTH);
-
-        let entity = commands.spawn((
-            Mesh3d(meshes.add(mesh)),
-            MeshMaterial3d(materials.add(materials::get_rainbow_material(i))),
-            Transform::from_xyz(0.0, 0.0, 0.0),
-        )).id();
-        
-        entities.push(entity);
+        parts.push(PartSpec {
+            part: LogoPart::ExteriorTriangle(i),
+            shape: PartShape::Triangle(p1, p2, p3),
+            transform: Transform::IDENTITY,
+        });
     }
-    
-    entities
-}
 
-fn create_interior_triangles(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Vec<Entity> {
-    let mut entities = Vec::new();
-    
     for i in 0..config::INTERIOR_TRIANGLES_COUNT {
         let base_angle = geometry::degrees_to_radians((i as f32) * 72.0 + 90.0);
-        let inner_radius = config::CIRCLE_RADIUS - config::CIRCLE_THICKNESS;
-
         let (p1, p2, p3) = geometry::calculate_interior_triangle_points(
             base_angle,
             inner_radius,
             config::LARGE_TRIANGLE_SIDE,
         );
-
         let triangle_center = geometry::calculate_triangle_centroid(p1, p2, p3);
-
-        let triangle_entity = commands.spawn((
-            Mesh3d(meshes.add(geometry::create_3d_triangle_mesh(p1, p2, p3, config::DEPTH))),
-            MeshMaterial3d(materials.add(materials::get_interior_triangle_material(i))),
-            Transform::from_xyz(0.0, 0.0, 0.0),
-        )).id();
-        
-        entities.push(triangle_entity);
-
-        let circle_entity = commands.spawn((
-            Mesh3d(meshes.add(geometry::create_3d_cylinder_mesh(
-                config::SMALL_CIRCLE_RADIUS,
-                config::DEPTH,
-                config::SMALL_CIRCLE_SEGMENTS,
-            ))),
-            MeshMaterial3d(materials.add(materials::get_small_circle_material())),
-            Transform::from_xyz(triangle_center.x, triangle_center.y, config::DEPTH / 2.0),
-        )).id();
-        
-        entities.push(circle_entity);
+        parts.push(PartSpec {
+            part: LogoPart::InteriorTriangle(i),
+            shape: PartShape::Triangle(p1, p2, p3),
+            transform: Transform::IDENTITY,
+        });
+        parts.push(PartSpec {
+            part: LogoPart::SmallCircle(i),
+            shape: PartShape::Cylinder { radius: config::SMALL_CIRCLE_RADIUS },
+            transform: Transform::from_xyz(triangle_center.x, triangle_center.y, config::DEPTH / 2.0),
+        });
     }
-    
-    entities
-}
-
-fn create_r_logo(
-    commands: &mut Commands,
-    meshes: &mut ResMut<Assets<Mesh>>,
-    materials: &mut ResMut<Assets<StandardMaterial>>,
-) -> Vec<Entity> {
-    let mut entities = Vec::new();
-    let r_material = materials.add(materials::get_r_logo_material());
 
     for part in geometry::get_all_r_parts() {
         if part.points.len() < 3 {
             continue;
         }
-
-        let entity = commands.spawn((
-            Mesh3d(meshes.add(geometry::create_3d_polygon_mesh(&part.points, config::DEPTH))),
-            MeshMaterial3d(r_material.clone()),
-            Transform::from_xyz(0.0, 0.0, 0.0),
-        )).id();
-        
-        entities.push(entity);
+        parts.push(PartSpec {
+            part: LogoPart::RPart(part.name),
+            shape: PartShape::Polygon(part.points),
+            transform: Transform::IDENTITY,
+        });
     }
-    
-    entities
+
+    parts
+}
+
+// === CRÉATION D'UNE PARTIE ===
+
+/// Crée l'entité d'une partie ; l'anneau et les petits cercles partagent
+/// leurs meshes par le cache
+fn spawn_part(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    material_cache: &mut MaterialCache,
+    mesh_cache: &mut MeshCache,
+    spec: PartSpec,
+) -> Entity {
+    let build = || spec.shape.mesh(config::CIRCLE_SEGMENTS, config::SMALL_CIRCLE_SEGMENTS);
+    let mesh = match spec.shape {
+        PartShape::Ring { outer_radius, inner_radius } => mesh_cache.get_or_add(
+            meshes,
+            MeshKey::ring(outer_radius, inner_radius, config::DEPTH, config::CIRCLE_SEGMENTS),
+            build,
+        ),
+        PartShape::Cylinder { radius } => mesh_cache.get_or_add(
+            meshes,
+            MeshKey::cylinder(radius, config::DEPTH, config::SMALL_CIRCLE_SEGMENTS),
+            build,
+        ),
+        PartShape::Triangle(..) | PartShape::Polygon(_) => meshes.add(build()),
+    };
+
+    commands.spawn((
+        Mesh3d(mesh),
+        MeshMaterial3d(material_cache.get_or_add(materials, spec.material())),
+        spec.transform,
+        spec.part,
+    )).id()
 }
 
 fn print_creation_summary() {
@@ -192,6 +254,28 @@ fn print_creation_summary() {
     println!("   • Clic gauche + souris : Rotation caméra");
     println!("   • Molette : Zoom");
     println!("   • Touche R : Réinitialiser vue");
+    println!("   • Touche P : Perspective / orthographique");
+    println!("   • Touche F : Cadrer le logo");
+    println!("   • Tab / G : Sélectionner / cadrer une partie");
+    println!("   • K / J / L : Keyframe / enregistrer / lire le chemin caméra");
+    println!("   • F5 / F9 : Sauvegarder / charger le chemin caméra");
+    println!("   • F12 : Exporter le chemin caméra image par image");
+    println!("   • Touche C : Caméra libre (WASD, Espace/Ctrl, Maj)");
+    println!("   • F2 / F3 : Écran partagé 1-2-4 vues / lier les vues");
+    println!("   • Survol / clic : Surligner / identifier une partie (et la faire vibrer)");
+    println!("   • Touche X / Page haut-bas : Vue éclatée");
+    println!("   • Touche T : Jouer la timeline");
+    println!("   • Touche I : Rejouer l'apparition");
+    println!("   • Touche U : Animer les matériaux");
+    println!("   • Touche M : Morphing R ↔ B");
+    println!("   • Touche O : Musique audio-réactive (assets/music.wav)");
+    println!("   • Touche B : Briser / réassembler le logo");
+    println!("   • Touche Y : Particules (étincelles, traînée du R)");
+    println!("   • Touche N : Thème de couleurs suivant");
+    println!("   • Touche H : Simuler un daltonisme (rapport d'accessibilité)");
+    println!("   • Touche V : Préréglages de matériaux (assets/materials.txt)");
+    println!("   • Touche Z : Fusionner / dissocier le logo (mesh unique)");
+    println!("   • Touche Q : Mur de logos (scène de charge)");
     println!("   • L'objet tourne automatiquement");
     println!("\n╚═══════════════════════════════════════════════════════════╝\n");
 }
@@ -0,0 +1,189 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/shatter.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de bris et de réassemblage du logo
+//!
+//! Chaque partie devient un corps rigide (boîte englobante) lancé depuis le
+//! centre du logo, qui tombe et rebondit sur le sol, puis revient à sa
+//! position de repos :
+//! - Touche B : Briser / réassembler
+//!
+//! La simulation se fait dans l'espace du parent : celui-ci ne tourne
+//! qu'autour de Y, le sol reste donc horizontal.
+
+use bevy::prelude::*;
+use crate::{config, geometry};
+use crate::physics::{DeterministicRng, PhysicsParams, RigidBody};
+use crate::systems::part_motion::RestTransform;
+use crate::systems::setup::LogoPart;
+use crate::timeline::Easing;
+
+/// Phase de l'effet
+#[derive(Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShatterPhase {
+    #[default]
+    Intact,
+    Shattered,
+    Reassembling,
+}
+
+/// Ressource contenant l'état du bris
+#[derive(Resource, Default)]
+pub struct ShatterState {
+    pub phase: ShatterPhase,
+    /// Temps écoulé depuis le début du réassemblage
+    pub reassemble_time: f32,
+}
+
+/// Corps rigide d'une partie brisée
+#[derive(Component, Clone, Copy)]
+pub struct ShatterBody {
+    pub body: RigidBody,
+    /// Centre de la boîte au repos (espace du parent)
+    pub rest_center: Vec3,
+    /// État du corps au début du réassemblage
+    pub from: RigidBody,
+}
+
+impl ShatterBody {
+    /// Transform de la partie pour un état donné du corps
+    pub fn part_transform(&self, body: &RigidBody, rest: &Transform) -> Transform {
+        let motion = Transform {
+            translation: body.position - body.orientation * self.rest_center,
+            rotation: body.orientation,
+            scale: Vec3::ONE,
+        };
+        motion.mul_transform(*rest)
+    }
+}
+
+/// Paramètres physiques de l'effet
+fn physics_params() -> PhysicsParams {
+    PhysicsParams {
+        gravity: Vec3::NEG_Y * config::SHATTER_GRAVITY,
+        ground_height: config::SHATTER_GROUND_Y,
+        restitution: 0.35,
+        friction: 0.15,
+    }
+}
+
+/// Graine stable par partie : le bris est identique à chaque fois
+fn part_seed(part: &LogoPart) -> u32 {
+    match part {
+        LogoPart::Ring => 1,
+        LogoPart::ExteriorTriangle(i) => 100 + *i as u32,
+        LogoPart::InteriorTriangle(i) => 200 + *i as u32,
+        LogoPart::SmallCircle(i) => 300 + *i as u32,
+        LogoPart::RPart(name) => name.bytes().fold(400, |seed, b| seed.wrapping_mul(31).wrapping_add(b as u32)),
+    }
+}
+
+/// Crée le corps rigide d'une partie à partir de sa boîte englobante au repos
+fn launch_body(part: &LogoPart, rest: &Transform, positions: &[Vec3]) -> ShatterBody {
+    let (min, max) = positions.iter().map(|p| rest.transform_point(*p)).fold(
+        (Vec3::splat(f32::INFINITY), Vec3::splat(f32::NEG_INFINITY)),
+        |(min, max), p| (min.min(p), max.max(p)),
+    );
+    let center = (min + max) * 0.5;
+
+    let mut rng = DeterministicRng::new(part_seed(part));
+    let radial = center.truncate().normalize_or_zero().extend(0.0);
+    let velocity = radial * rng.range(150.0, 300.0)
+        + Vec3::Y * rng.range(150.0, 350.0)
+        + Vec3::Z * rng.range(-100.0, 200.0);
+    let angular_velocity = Vec3::new(rng.range(-6.0, 6.0), rng.range(-6.0, 6.0), rng.range(-6.0, 6.0));
+
+    let body = RigidBody {
+        position: center,
+        orientation: Quat::IDENTITY,
+        velocity,
+        angular_velocity,
+        half_extents: ((max - min) * 0.5).max(Vec3::splat(0.5)),
+    };
+    ShatterBody { body, rest_center: center, from: body }
+}
+
+/// Système de commande : bris, réassemblage et fin de l'effet
+#[allow(clippy::type_complexity)]
+pub fn shatter_control_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    meshes: Res<Assets<Mesh>>,
+    mut state: ResMut<ShatterState>,
+    parts: Query<(Entity, &LogoPart, &RestTransform, &Mesh3d)>,
+    mut bodies: Query<(Entity, &mut ShatterBody)>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyB) {
+        match state.phase {
+            ShatterPhase::Intact => {
+                for (entity, part, rest, mesh_handle) in parts.iter() {
+                    let Some(mesh) = meshes.get(&mesh_handle.0) else { continue };
+                    let positions = geometry::mesh_positions(mesh);
+                    if positions.is_empty() {
+                        continue;
+                    }
+                    commands.entity(entity).insert(launch_body(part, &rest.0, &positions));
+                }
+                state.phase = ShatterPhase::Shattered;
+                println!("💥 Logo brisé");
+            }
+            ShatterPhase::Shattered => {
+                for (_, mut shatter) in bodies.iter_mut() {
+                    shatter.from = shatter.body;
+                }
+                state.phase = ShatterPhase::Reassembling;
+                state.reassemble_time = 0.0;
+                println!("🧩 Réassemblage du logo");
+            }
+            ShatterPhase::Reassembling => {}
+        }
+    }
+
+    // === FIN DU RÉASSEMBLAGE ===
+    if state.phase == ShatterPhase::Reassembling {
+        state.reassemble_time += time.delta_secs();
+        if state.reassemble_time >= config::REASSEMBLE_DURATION {
+            for (entity, _) in bodies.iter() {
+                commands.entity(entity).remove::<ShatterBody>();
+            }
+            state.phase = ShatterPhase::Intact;
+        }
+    }
+}
+
+/// Intègre les corps rigides à pas fixe
+pub fn shatter_integration_system(
+    time: Res<Time<Fixed>>,
+    state: Res<ShatterState>,
+    mut bodies: Query<&mut ShatterBody>,
+) {
+    if state.phase != ShatterPhase::Shattered {
+        return;
+    }
+    let params = physics_params();
+    let dt = time.delta_secs();
+    for mut shatter in bodies.iter_mut() {
+        shatter.body.step(&params, dt);
+    }
+}
+
+/// Place les parties brisées (remplace le résultat des autres effets)
+pub fn shatter_apply_system(
+    state: Res<ShatterState>,
+    mut query: Query<(&ShatterBody, &RestTransform, &mut Transform)>,
+) {
+    let u = Easing::EaseInOut.apply((state.reassemble_time / config::REASSEMBLE_DURATION).clamp(0.0, 1.0));
+    for (shatter, rest, mut transform) in query.iter_mut() {
+        let body = match state.phase {
+            ShatterPhase::Reassembling => RigidBody {
+                position: shatter.from.position.lerp(shatter.rest_center, u),
+                orientation: shatter.from.orientation.slerp(Quat::IDENTITY, u),
+                ..shatter.from
+            },
+            _ => shatter.body,
+        };
+        *transform = shatter.part_transform(&body, &rest.0);
+    }
+}
@@ -0,0 +1,180 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/spring.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de ressorts amortis (effet « wobble »)
+//!
+//! Un clic sur une partie ou un mouvement vif de la caméra donne une
+//! impulsion : les triangles extérieurs tremblent et le R rebondit avant de
+//! se stabiliser. Les ressorts sont intégrés à pas fixe (`FixedUpdate`).
+
+use bevy::prelude::*;
+use bevy::input::mouse::MouseMotion;
+use crate::systems::camera::RotatingObject;
+use crate::systems::part_motion::{PartPivot, RestTransform};
+use crate::systems::picking::HoveredPart;
+use crate::systems::setup::LogoPart;
+
+/// Déplacement souris (pixels par image) à partir duquel la caméra est « lancée »
+const FLICK_THRESHOLD: f32 = 40.0;
+
+/// Vitesse communiquée par un clic sur une partie
+const CLICK_IMPULSE: f32 = 150.0;
+
+/// Ressort amorti ramenant une partie à sa position de repos
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Spring {
+    /// Raideur (rappel par unité de déplacement)
+    pub stiffness: f32,
+    /// Amortissement (freinage par unité de vitesse)
+    pub damping: f32,
+    /// Déplacement actuel par rapport au repos
+    pub position: Vec3,
+    pub velocity: Vec3,
+}
+
+impl Spring {
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self { stiffness, damping, position: Vec3::ZERO, velocity: Vec3::ZERO }
+    }
+
+    /// Ressort par défaut de chaque catégorie de partie
+    pub fn for_part(part: &LogoPart) -> Self {
+        match part {
+            LogoPart::ExteriorTriangle(_) => Spring::new(300.0, 6.0),
+            LogoPart::RPart(_) => Spring::new(120.0, 4.0),
+            _ => Spring::new(200.0, 10.0),
+        }
+    }
+
+    /// Avance le ressort d'un pas `dt` (Euler semi-implicite)
+    pub fn step(&mut self, dt: f32) {
+        let acceleration = -self.stiffness * self.position - self.damping * self.velocity;
+        self.velocity += acceleration * dt;
+        self.position += self.velocity * dt;
+    }
+}
+
+/// Ajoute un ressort aux nouvelles parties
+pub fn init_spring_system(
+    mut commands: Commands,
+    query: Query<(Entity, &LogoPart), Without<Spring>>,
+) {
+    for (entity, part) in query.iter() {
+        commands.entity(entity).insert(Spring::for_part(part));
+    }
+}
+
+/// Direction de l'impulsion d'une partie : les triangles partent vers
+/// l'extérieur avec un léger écart tangentiel, le R rebondit en Z
+fn wobble_direction(part: &LogoPart, center: Vec3) -> Vec3 {
+    match part {
+        LogoPart::ExteriorTriangle(i) => {
+            let radial = center.truncate().normalize_or_zero();
+            let tangent = radial.perp() * (*i as f32 * 12.9898).sin();
+            (radial + tangent * 0.5).extend(0.0)
+        }
+        LogoPart::RPart(_) => Vec3::Z,
+        _ => Vec3::ZERO,
+    }
+}
+
+/// Système déclenchant les impulsions (clic, caméra lancée)
+pub fn wobble_trigger_system(
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    mut mouse_motion: EventReader<MouseMotion>,
+    hovered: Res<HoveredPart>,
+    logos: Query<&GlobalTransform, With<RotatingObject>>,
+    mut query: Query<(Entity, &LogoPart, &RestTransform, &PartPivot, &mut Spring)>,
+) {
+    let flick = if mouse_button.pressed(MouseButton::Left) {
+        mouse_motion.read().map(|m| m.delta.length()).fold(0.0, f32::max)
+    } else {
+        mouse_motion.clear();
+        0.0
+    };
+    let clicked = hovered.0.filter(|_| mouse_button.just_pressed(MouseButton::Left));
+
+    let mut strength = 0.0;
+    if flick > FLICK_THRESHOLD {
+        strength = flick;
+    }
+    if clicked.is_some() {
+        strength = strength.max(CLICK_IMPULSE * 0.5);
+    }
+    if strength == 0.0 {
+        return;
+    }
+
+    // Les ressorts travaillent dans l'espace du logo, qui tourne
+    let logo_from_world = logos
+        .single()
+        .map(|logo| logo.compute_transform().rotation.inverse())
+        .unwrap_or(Quat::IDENTITY);
+
+    for (entity, part, rest, pivot, mut spring) in query.iter_mut() {
+        let center = rest.0.transform_point(pivot.0);
+        spring.velocity += wobble_direction(part, center) * strength;
+
+        // La partie cliquée est enfoncée le long de la normale touchée
+        if let Some(hit) = clicked.filter(|hit| hit.entity == entity) {
+            spring.velocity -= logo_from_world * hit.normal * CLICK_IMPULSE;
+        }
+    }
+}
+
+/// Système d'intégration des ressorts (pas fixe)
+pub fn spring_integration_system(time: Res<Time<Fixed>>, mut query: Query<&mut Spring>) {
+    let dt = time.delta_secs();
+    for mut spring in query.iter_mut() {
+        if spring.position == Vec3::ZERO && spring.velocity == Vec3::ZERO {
+            continue;
+        }
+        spring.step(dt);
+
+        // Mise au repos une fois le mouvement imperceptible
+        if spring.position.length_squared() < 1e-6 && spring.velocity.length_squared() < 1e-4 {
+            spring.position = Vec3::ZERO;
+            spring.velocity = Vec3::ZERO;
+        }
+    }
+}
+
+/// Système appliquant le déplacement des ressorts aux parties
+pub fn spring_apply_system(mut query: Query<(&Spring, &mut Transform)>) {
+    for (spring, mut transform) in query.iter_mut() {
+        transform.translation += spring.position;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+
+    #[test]
+    fn springs_settle_and_stay_bounded() {
+        let dt = (1.0 / config::FIXED_UPDATE_HZ) as f32;
+        let kick = 600.0;
+        for part in [LogoPart::ExteriorTriangle(0), LogoPart::RPart("Haut du R"), LogoPart::Ring] {
+            let mut spring = Spring::for_part(&part);
+            spring.velocity = Vec3::new(kick, 0.0, kick);
+
+            // Sans amortissement, l'amplitude serait v0 / ω : elle ne peut que décroître
+            let bound = spring.velocity.length() / spring.stiffness.sqrt();
+            let mut settled_at = None;
+            for i in 0..(10.0 / dt) as usize {
+                spring.step(dt);
+                assert!(spring.position.length() <= bound * 1.05, "{part} : amplitude {}", spring.position.length());
+                if settled_at.is_none()
+                    && spring.position.length_squared() < 1e-6
+                    && spring.velocity.length_squared() < 1e-4
+                {
+                    settled_at = Some(i as f32 * dt);
+                }
+            }
+            assert!(settled_at.is_some_and(|t| t < 8.0), "{part} : pas de retour au repos");
+            assert!(spring.position.length() < 1e-3);
+        }
+    }
+}
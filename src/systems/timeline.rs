@@ -0,0 +1,184 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/timeline.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module de lecture de timeline
+//!
+//! Applique les pistes de `crate::timeline` aux parties du logo :
+//! - Touche T : Charger et jouer la timeline (`config::TIMELINE_FILE`)
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::config;
+use crate::timeline::{Timeline, TrackProperty};
+use crate::systems::explode::ExplodeState;
+use crate::systems::part_motion::{apply_about_pivot, PartPivot};
+use crate::systems::picking::HoverHighlight;
+use crate::systems::setup::LogoPart;
+
+/// Ressource de lecture de la timeline
+#[derive(Resource, Default)]
+pub struct TimelinePlayer {
+    pub timeline: Timeline,
+    /// Temps de lecture en secondes
+    pub time: f32,
+    pub playing: bool,
+    /// Reprendre au début une fois la fin atteinte
+    pub looping: bool,
+}
+
+/// Marque une partie dont le matériau a été dupliqué pour être animé seul
+#[derive(Component)]
+pub struct UniqueMaterial;
+
+/// Système de chargement et d'avancement de la timeline
+pub fn timeline_clock_system(
+    time: Res<Time>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut player: ResMut<TimelinePlayer>,
+) {
+    if keyboard.just_pressed(KeyCode::KeyT) {
+        match Timeline::load(config::TIMELINE_FILE) {
+            Ok(timeline) => {
+                println!("🎬 Timeline chargée : {} pistes, {:.1} s", timeline.tracks.len(), timeline.duration());
+                player.timeline = timeline;
+                player.time = 0.0;
+                player.playing = true;
+            }
+            Err(e) => println!("⚠ Timeline illisible ({}) : {}", config::TIMELINE_FILE, e),
+        }
+        return;
+    }
+
+    if !player.playing {
+        return;
+    }
+    player.time += time.delta_secs();
+    let duration = player.timeline.duration();
+    if player.time > duration {
+        if player.looping && duration > 0.0 {
+            player.time %= duration;
+        } else {
+            player.playing = false;
+        }
+    }
+}
+
+/// Système appliquant les pistes de transform (et de vue éclatée)
+pub fn timeline_transform_system(
+    player: Res<TimelinePlayer>,
+    mut explode: ResMut<ExplodeState>,
+    mut query: Query<(&LogoPart, &PartPivot, &mut Transform)>,
+) {
+    if !player.playing {
+        return;
+    }
+
+    for track in &player.timeline.tracks {
+        if track.property == TrackProperty::Explode {
+            if let Some(value) = track.sample(player.time, 0) {
+                explode.factor = value.x.clamp(0.0, 1.0);
+                explode.target = explode.factor;
+            }
+            continue;
+        }
+
+        for (part, pivot, mut transform) in query.iter_mut() {
+            let Some(rank) = track.target.matches(part) else {
+                continue;
+            };
+            let Some(value) = track.sample(player.time, rank) else {
+                continue;
+            };
+            let value = value.truncate();
+            match track.property {
+                TrackProperty::Translation => transform.translation += value,
+                TrackProperty::Rotation => {
+                    let rotation = Quat::from_euler(
+                        EulerRot::XYZ,
+                        value.x.to_radians(),
+                        value.y.to_radians(),
+                        value.z.to_radians(),
+                    );
+                    apply_about_pivot(&mut transform, pivot.0, rotation, Vec3::ONE);
+                }
+                TrackProperty::Scale => apply_about_pivot(&mut transform, pivot.0, Quat::IDENTITY, value),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Système appliquant les pistes de matériau (couleur de base, émission)
+///
+/// Une partie animée reçoit d'abord sa propre copie du matériau, pour ne pas
+/// modifier les parties qui le partagent. Sur une partie surlignée, c'est le
+/// matériau restauré à la fin du survol qui est copié et animé ; la copie
+/// de surbrillance suit la couleur de base.
+#[allow(clippy::type_complexity)]
+pub fn timeline_material_system(
+    mut commands: Commands,
+    player: Res<TimelinePlayer>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut query: Query<(
+        Entity,
+        &LogoPart,
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&mut HoverHighlight>,
+        Has<UniqueMaterial>,
+    )>,
+) {
+    if !player.playing {
+        return;
+    }
+
+    // Copies créées pendant cette image (le marqueur n'est posé qu'à la fin)
+    let mut copied: HashSet<Entity> = HashSet::new();
+    for track in &player.timeline.tracks {
+        if !matches!(track.property, TrackProperty::BaseColor | TrackProperty::Emissive) {
+            continue;
+        }
+
+        for (entity, part, mut material_handle, mut highlight, unique) in query.iter_mut() {
+            let Some(rank) = track.target.matches(part) else {
+                continue;
+            };
+            let Some(value) = track.sample(player.time, rank) else {
+                continue;
+            };
+
+            // Matériau propre à la partie, hors surbrillance
+            let owned = match highlight.as_mut() {
+                Some(highlight) => &mut highlight.original,
+                None => &mut material_handle.0,
+            };
+            if !unique && copied.insert(entity) {
+                let Some(copy) = materials.get(&*owned).cloned() else {
+                    continue;
+                };
+                *owned = materials.add(copy);
+                commands.entity(entity).insert(UniqueMaterial);
+            }
+            let owned = owned.clone();
+
+            let Some(material) = materials.get_mut(&owned) else {
+                continue;
+            };
+            match track.property {
+                TrackProperty::BaseColor => {
+                    let color = Color::srgba(value.x, value.y, value.z, value.w);
+                    material.base_color = color;
+                    if highlight.is_some()
+                        && let Some(displayed) = materials.get_mut(&material_handle.0)
+                    {
+                        displayed.base_color = color;
+                    }
+                }
+                TrackProperty::Emissive => {
+                    material.emissive = LinearRgba::rgb(value.x, value.y, value.z);
+                }
+                _ => {}
+            }
+        }
+    }
+}
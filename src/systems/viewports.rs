@@ -0,0 +1,147 @@
+// ═══════════════════════════════════════════════════════════════════════════
+//         NOUVEAU FICHIER: src/systems/viewports.rs
+// ═══════════════════════════════════════════════════════════════════════════
+
+//! Module d'écran partagé (plusieurs vues dans une fenêtre)
+//!
+//! Chaque vue possède sa propre `OrbitCamera` regardant le même logo :
+//! - F2 : Passer de 1 à 2 puis 4 vues
+//! - F3 : Lier / délier les contrôles des vues
+//!
+//! Quand les vues sont indépendantes, la souris et le clavier agissent sur
+//! la vue située sous le curseur.
+
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::PrimaryWindow;
+use std::f32::consts::FRAC_PI_2;
+use crate::config;
+use crate::systems::camera::{OrbitCamera, ProjectionMode};
+use crate::systems::framing::FrameLogoRequest;
+
+/// Nombre maximal de vues simultanées
+pub const MAX_VIEWPORTS: usize = 4;
+
+/// Composant indiquant l'emplacement d'une caméra dans la grille des vues
+#[derive(Component, Clone, Copy, PartialEq, Eq)]
+pub struct ViewportSlot(pub usize);
+
+/// Ressource décrivant la disposition des vues
+#[derive(Resource)]
+pub struct ViewportLayout {
+    /// Nombre de vues affichées (1, 2 ou 4)
+    pub count: usize,
+    /// Contrôles liés : toutes les vues reçoivent les mêmes commandes
+    pub linked: bool,
+}
+
+impl Default for ViewportLayout {
+    fn default() -> Self {
+        Self { count: 1, linked: false }
+    }
+}
+
+/// Caméra orbitale prédéfinie pour chaque emplacement
+///
+/// 0 : perspective, 1 : face, 2 : dessus, 3 : côté.
+pub fn viewport_preset(slot: usize) -> OrbitCamera {
+    let (yaw, pitch, projection_mode) = match slot {
+        1 => (0.0, 0.0, ProjectionMode::Orthographic),
+        2 => (0.0, FRAC_PI_2, ProjectionMode::Orthographic),
+        3 => (FRAC_PI_2, 0.0, ProjectionMode::Orthographic),
+        _ => return OrbitCamera::default(),
+    };
+    OrbitCamera {
+        yaw,
+        pitch,
+        home_yaw: yaw,
+        home_pitch: pitch,
+        max_pitch: FRAC_PI_2.max(config::CAMERA_MAX_PITCH),
+        projection_mode,
+        ..default()
+    }
+}
+
+/// Rectangle (position, taille) en pixels physiques d'une vue
+///
+/// Retourne `None` si l'emplacement n'est pas utilisé par la disposition.
+pub fn viewport_rect(slot: usize, count: usize, window_size: UVec2) -> Option<(UVec2, UVec2)> {
+    match count {
+        1 if slot == 0 => Some((UVec2::ZERO, window_size)),
+        2 if slot < 2 => {
+            let size = UVec2::new(window_size.x / 2, window_size.y);
+            Some((UVec2::new(slot as u32 * size.x, 0), size))
+        }
+        4 if slot < 4 => {
+            let size = window_size / 2;
+            let cell = UVec2::new(slot as u32 % 2, slot as u32 / 2);
+            Some((cell * size, size))
+        }
+        _ => None,
+    }
+}
+
+/// Indique si une caméra doit réagir aux commandes de l'utilisateur
+pub fn camera_has_input(layout: &ViewportLayout, camera: &Camera, cursor: Option<Vec2>) -> bool {
+    if !camera.is_active {
+        return false;
+    }
+    if layout.linked || layout.count == 1 {
+        return true;
+    }
+    match (cursor, camera.logical_viewport_rect()) {
+        (Some(cursor), Some(rect)) => rect.contains(cursor),
+        _ => false,
+    }
+}
+
+/// Système de disposition des vues
+pub fn viewport_layout_system(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut layout: ResMut<ViewportLayout>,
+    mut last_size: Local<UVec2>,
+    mut frame_requests: EventWriter<FrameLogoRequest>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&ViewportSlot, &mut Camera)>,
+) {
+    // === CHANGEMENT DE DISPOSITION ===
+    if keyboard.just_pressed(KeyCode::F2) {
+        layout.count = match layout.count {
+            1 => 2,
+            2 => MAX_VIEWPORTS,
+            _ => 1,
+        };
+        println!("🪟 {} vue(s)", layout.count);
+    }
+    if keyboard.just_pressed(KeyCode::F3) {
+        layout.linked = !layout.linked;
+        println!("🔗 Contrôles {}", if layout.linked { "liés" } else { "indépendants" });
+    }
+
+    // === MISE À JOUR DES VIEWPORTS ===
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let window_size = window.physical_size();
+    if !layout.is_changed() && window_size == *last_size {
+        return;
+    }
+    *last_size = window_size;
+
+    for (slot, mut camera) in cameras.iter_mut() {
+        match viewport_rect(slot.0, layout.count, window_size) {
+            Some((position, size)) => {
+                camera.is_active = true;
+                camera.viewport = (layout.count > 1).then(|| Viewport {
+                    physical_position: position,
+                    physical_size: size,
+                    ..default()
+                });
+            }
+            None => camera.is_active = false,
+        }
+    }
+
+    // Le ratio des vues a changé : on recadre le logo
+    frame_requests.write(FrameLogoRequest);
+}
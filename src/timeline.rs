@@ -0,0 +1,362 @@
+// ╔══════════════════════════════════════════════════════════════════════════╗
+// ║                        FICHIER: src/timeline.rs                          ║
+// ╚══════════════════════════════════════════════════════════════════════════╝
+
+//! Moteur de timeline : pistes de keyframes ciblant les parties du logo
+//!
+//! Format texte (une commande par ligne, `#` pour les commentaires) :
+//!
+//! ```text
+//! track <cible> <propriété> [stagger=<s>]
+//! key <temps> <valeurs...> [courbe]
+//! ```
+//!
+//! Cibles : `logo`, `ring`, `exterior:<i|*>`, `interior:<i|*>`,
+//! `circle:<i|*>`, `r:<nom|*>` (les espaces du nom s'écrivent `_`).
+//!
+//! Propriétés : `translation` (x y z, décalage), `rotation` (x y z en
+//! degrés), `scale` (x y z, facteur), `color` (r g b a), `emissive` (r g b),
+//! `explode` (facteur de vue éclatée, seule propriété de la cible `logo`).
+//!
+//! Courbes : `linear`, `step`, `ease_in`, `ease_out`, `ease_in_out`,
+//! `ease_out_back`. La courbe d'une keyframe s'applique jusqu'à la suivante.
+
+use bevy::prelude::*;
+use std::fs;
+use std::io;
+use std::path::Path;
+use crate::{config, geometry};
+use crate::file_io::line_error;
+use crate::systems::setup::LogoPart;
+
+/// Courbe d'interpolation entre deux keyframes
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Easing {
+    #[default]
+    Linear,
+    Step,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+    /// Dépasse légèrement la valeur finale avant de s'y poser (effet « pop »)
+    EaseOutBack,
+}
+
+impl Easing {
+    /// Applique la courbe à un paramètre u dans [0, 1]
+    pub fn apply(self, u: f32) -> f32 {
+        let u = u.clamp(0.0, 1.0);
+        match self {
+            Easing::Linear => u,
+            Easing::Step => if u < 1.0 { 0.0 } else { 1.0 },
+            Easing::EaseIn => u * u,
+            Easing::EaseOut => 1.0 - (1.0 - u) * (1.0 - u),
+            Easing::EaseInOut => u * u * (3.0 - 2.0 * u),
+            Easing::EaseOutBack => {
+                let c1 = 1.70158;
+                let c3 = c1 + 1.0;
+                1.0 + c3 * (u - 1.0).powi(3) + c1 * (u - 1.0).powi(2)
+            }
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "linear" => Some(Easing::Linear),
+            "step" => Some(Easing::Step),
+            "ease_in" => Some(Easing::EaseIn),
+            "ease_out" => Some(Easing::EaseOut),
+            "ease_in_out" => Some(Easing::EaseInOut),
+            "ease_out_back" => Some(Easing::EaseOutBack),
+            _ => None,
+        }
+    }
+}
+
+/// Propriété animée par une piste
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackProperty {
+    Translation,
+    Rotation,
+    Scale,
+    BaseColor,
+    Emissive,
+    Explode,
+}
+
+impl TrackProperty {
+    /// Nombre de valeurs attendues par keyframe
+    pub fn arity(self) -> usize {
+        match self {
+            TrackProperty::BaseColor => 4,
+            TrackProperty::Explode => 1,
+            _ => 3,
+        }
+    }
+
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "translation" => Some(TrackProperty::Translation),
+            "rotation" => Some(TrackProperty::Rotation),
+            "scale" => Some(TrackProperty::Scale),
+            "color" => Some(TrackProperty::BaseColor),
+            "emissive" => Some(TrackProperty::Emissive),
+            "explode" => Some(TrackProperty::Explode),
+            _ => None,
+        }
+    }
+}
+
+/// Sélection des parties ciblées par une piste (`None` : toutes les parties de la catégorie)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PartSelector {
+    Logo,
+    Ring,
+    Exterior(Option<usize>),
+    Interior(Option<usize>),
+    Circle(Option<usize>),
+    R(Option<String>),
+}
+
+impl PartSelector {
+    /// Indique si la partie est ciblée, et retourne son rang pour le décalage (stagger)
+    pub fn matches(&self, part: &LogoPart) -> Option<usize> {
+        fn indexed(filter: &Option<usize>, index: usize) -> Option<usize> {
+            filter.is_none_or(|f| f == index).then_some(index)
+        }
+        match (self, part) {
+            (PartSelector::Ring, LogoPart::Ring) => Some(0),
+            (PartSelector::Exterior(f), LogoPart::ExteriorTriangle(i)) => indexed(f, *i),
+            (PartSelector::Interior(f), LogoPart::InteriorTriangle(i)) => indexed(f, *i),
+            (PartSelector::Circle(f), LogoPart::SmallCircle(i)) => indexed(f, *i),
+            (PartSelector::R(filter), LogoPart::RPart(name)) => {
+                let rank = geometry::R_PART_NAMES.iter().position(|n| n == name).unwrap_or(0);
+                filter.as_ref().is_none_or(|f| f == name).then_some(rank)
+            }
+            _ => None,
+        }
+    }
+
+    /// Rang le plus élevé parmi les parties ciblées
+    pub fn max_rank(&self) -> usize {
+        let last = |filter: &Option<usize>, count: usize| filter.unwrap_or(count.saturating_sub(1));
+        match self {
+            PartSelector::Logo | PartSelector::Ring => 0,
+            PartSelector::Exterior(f) => last(f, config::EXTERIOR_TRIANGLES_COUNT),
+            PartSelector::Interior(f) | PartSelector::Circle(f) => last(f, config::INTERIOR_TRIANGLES_COUNT),
+            PartSelector::R(name) => match name {
+                Some(name) => geometry::R_PART_NAMES.iter().position(|n| n == name).unwrap_or(0),
+                None => geometry::R_PART_NAMES.len() - 1,
+            },
+        }
+    }
+
+    /// Lit une cible ; un indice hors limites ou un nom de partie inconnu est refusé
+    fn parse(text: &str) -> Option<Self> {
+        fn index(text: &str, count: usize) -> Option<Option<usize>> {
+            if text == "*" {
+                return Some(None);
+            }
+            text.parse().ok().filter(|&i| i < count).map(Some)
+        }
+        let (kind, arg) = text.split_once(':').unwrap_or((text, "*"));
+        match kind {
+            "logo" => Some(PartSelector::Logo),
+            "ring" => Some(PartSelector::Ring),
+            "exterior" => index(arg, config::EXTERIOR_TRIANGLES_COUNT).map(PartSelector::Exterior),
+            "interior" => index(arg, config::INTERIOR_TRIANGLES_COUNT).map(PartSelector::Interior),
+            "circle" => index(arg, config::INTERIOR_TRIANGLES_COUNT).map(PartSelector::Circle),
+            "r" if arg == "*" => Some(PartSelector::R(None)),
+            "r" => {
+                let name = arg.replace('_', " ");
+                geometry::R_PART_NAMES.contains(&name.as_str()).then_some(PartSelector::R(Some(name)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Keyframe d'une piste
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Keyframe {
+    pub time: f32,
+    /// Valeurs (seules les `arity()` premières sont utilisées)
+    pub value: Vec4,
+    /// Courbe vers la keyframe suivante
+    pub easing: Easing,
+}
+
+/// Piste : suite de keyframes pour une propriété d'un ensemble de parties
+#[derive(Clone, Debug, PartialEq)]
+pub struct Track {
+    pub target: PartSelector,
+    pub property: TrackProperty,
+    /// Décalage temporel entre deux parties successives ciblées
+    pub stagger: f32,
+    pub keyframes: Vec<Keyframe>,
+}
+
+impl Track {
+    /// Échantillonne la piste à l'instant `time` pour la partie de rang `rank`
+    pub fn sample(&self, time: f32, rank: usize) -> Option<Vec4> {
+        let time = time - self.stagger * rank as f32;
+        let first = self.keyframes.first()?;
+        if time <= first.time {
+            return Some(first.value);
+        }
+        for pair in self.keyframes.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+            if time < b.time {
+                let u = (time - a.time) / (b.time - a.time).max(f32::EPSILON);
+                return Some(a.value.lerp(b.value, a.easing.apply(u)));
+            }
+        }
+        self.keyframes.last().map(|k| k.value)
+    }
+
+    /// Instant de fin de la piste, décalages compris
+    pub fn end_time(&self) -> f32 {
+        self.keyframes.last().map(|k| k.time).unwrap_or(0.0) + self.stagger * self.target.max_rank() as f32
+    }
+}
+
+/// Timeline : ensemble de pistes jouées ensemble
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timeline {
+    pub tracks: Vec<Track>,
+}
+
+impl Timeline {
+    /// Lit une timeline au format texte
+    pub fn from_text(text: &str) -> io::Result<Self> {
+        let mut timeline = Timeline::default();
+        for (number, line) in text.lines().enumerate() {
+            let error = |message: &str| line_error(number, message);
+            let words: Vec<&str> = line.split('#').next().unwrap_or("").split_whitespace().collect();
+            match words.as_slice() {
+                [] => {}
+                ["track", target, property, options @ ..] => {
+                    let target = PartSelector::parse(target).ok_or_else(|| error("cible inconnue"))?;
+                    let property = TrackProperty::parse(property).ok_or_else(|| error("propriété inconnue"))?;
+                    // `logo` ne désigne aucune partie : seule l'explosion s'applique au logo entier
+                    if target == PartSelector::Logo && property != TrackProperty::Explode {
+                        return Err(error("la cible logo n'accepte que explode"));
+                    }
+                    let mut stagger = 0.0;
+                    for option in options {
+                        match option.split_once('=') {
+                            Some(("stagger", value)) => {
+                                stagger = value.parse().map_err(|_| error("stagger invalide"))?;
+                            }
+                            _ => return Err(error("option inconnue")),
+                        }
+                    }
+                    timeline.tracks.push(Track { target, property, stagger, keyframes: Vec::new() });
+                }
+                ["key", time, rest @ ..] => {
+                    let track = timeline.tracks.last_mut().ok_or_else(|| error("keyframe hors piste"))?;
+                    let arity = track.property.arity();
+                    let easing = match rest.get(arity) {
+                        Some(name) => Easing::parse(name).ok_or_else(|| error("courbe inconnue"))?,
+                        None => Easing::Linear,
+                    };
+                    if rest.len() < arity || rest.len() > arity + 1 {
+                        return Err(error(&format!("{} valeurs attendues", arity)));
+                    }
+                    let mut value = Vec4::ZERO;
+                    for (i, text) in rest[..arity].iter().enumerate() {
+                        value[i] = text.parse().map_err(|_| error("valeur invalide"))?;
+                    }
+                    let time: f32 = time.parse().map_err(|_| error("temps invalide"))?;
+                    if track.keyframes.last().is_some_and(|k| k.time > time) {
+                        return Err(error("keyframes non triées"));
+                    }
+                    track.keyframes.push(Keyframe { time, value, easing });
+                }
+                _ => return Err(error("commande inconnue")),
+            }
+        }
+        Ok(timeline)
+    }
+
+    /// Charge une timeline depuis un fichier
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_text(&fs::read_to_string(path)?)
+    }
+
+    /// Durée totale de la timeline
+    pub fn duration(&self) -> f32 {
+        self.tracks.iter().map(Track::end_time).fold(0.0, f32::max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error_line(text: &str) -> String {
+        Timeline::from_text(text).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn from_text_reports_errors_with_line_numbers() {
+        assert!(error_line("track ring scale\nkey 0 1 1 1\nbonjour").starts_with("ligne 3"));
+        assert!(error_line("track triangle:* scale").contains("cible inconnue"));
+        assert!(error_line("track exterior:99 scale").contains("cible inconnue"));
+        assert!(error_line("track r:Queue_du_R scale").contains("cible inconnue"));
+        assert!(error_line("track ring taille").contains("propriété inconnue"));
+        assert!(error_line("track logo rotation").contains("n'accepte que explode"));
+        assert!(Timeline::from_text("track logo explode\nkey 0 1").is_ok());
+        assert!(error_line("key 0 1 1 1").contains("keyframe hors piste"));
+        assert!(error_line("track ring scale\nkey 0 1 1").contains("3 valeurs attendues"));
+        assert!(error_line("track ring scale\nkey 0 1 1 1 rebond").contains("courbe inconnue"));
+        assert!(error_line("track ring scale\nkey 1 1 1 1\nkey 0.5 2 2 2").contains("keyframes non triées"));
+
+        let timeline = Timeline::from_text("# commentaire\ntrack r:Haut_du_R emissive\nkey 0 0 0 0 step\n").unwrap();
+        assert_eq!(timeline.tracks[0].target, PartSelector::R(Some("Haut du R".to_string())));
+        assert_eq!(timeline.tracks[0].keyframes[0].easing, Easing::Step);
+    }
+
+    #[test]
+    fn example_file_parses() {
+        let timeline = Timeline::from_text(include_str!("../assets/timeline.txt")).unwrap();
+        assert_eq!(timeline.tracks.len(), 3);
+    }
+
+    #[test]
+    fn sample_applies_stagger_per_rank() {
+        let timeline = Timeline::from_text("track exterior:* scale stagger=0.5\nkey 1 0 0 0\nkey 2 2 2 2").unwrap();
+        let track = &timeline.tracks[0];
+
+        // Rang 0 : interpolation linéaire entre 1 s et 2 s
+        assert_eq!(track.sample(0.0, 0), Some(Vec4::ZERO));
+        assert_eq!(track.sample(1.5, 0), Some(Vec4::new(1.0, 1.0, 1.0, 0.0)));
+        assert_eq!(track.sample(5.0, 0), Some(Vec4::new(2.0, 2.0, 2.0, 0.0)));
+        // Rang 2 : même courbe décalée d'une seconde
+        assert_eq!(track.sample(1.5, 2), Some(Vec4::ZERO));
+        assert_eq!(track.sample(2.5, 2), Some(Vec4::new(1.0, 1.0, 1.0, 0.0)));
+
+        let last_rank = config::EXTERIOR_TRIANGLES_COUNT - 1;
+        assert_eq!(track.target.max_rank(), last_rank);
+        assert!((track.end_time() - (2.0 + 0.5 * last_rank as f32)).abs() < 1e-4);
+    }
+
+    #[test]
+    fn easings_keep_endpoints() {
+        for easing in [
+            Easing::Linear,
+            Easing::Step,
+            Easing::EaseIn,
+            Easing::EaseOut,
+            Easing::EaseInOut,
+            Easing::EaseOutBack,
+        ] {
+            assert!(easing.apply(0.0).abs() < 1e-6, "{easing:?}");
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-6, "{easing:?}");
+            // Hors de [0, 1], le paramètre est borné
+            assert_eq!(easing.apply(-1.0), easing.apply(0.0));
+            assert_eq!(easing.apply(2.0), easing.apply(1.0));
+        }
+        assert!(Easing::EaseOutBack.apply(0.8) > 1.0);
+    }
+}